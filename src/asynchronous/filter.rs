@@ -0,0 +1,166 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! The asynchronous filter implementation
+//!
+use std::future::Future;
+
+use futures::future::{BoxFuture, FutureExt};
+
+pub use crate::asynchronous::ops::and::AsyncAnd;
+pub use crate::asynchronous::ops::not::AsyncNot;
+pub use crate::asynchronous::ops::or::AsyncOr;
+
+/// Trait for filters which decide asynchronously
+///
+/// This is useful if the decision needs to wait for something, for example a database lookup.
+/// The returned future is boxed, so the trait can be implemented without `async fn` in traits:
+///
+/// ```
+/// use futures::future::{BoxFuture, FutureExt};
+/// use filters::asynchronous::filter::FilterAsync;
+///
+/// struct IsKnownUser {
+///     users: Vec<String>,
+/// }
+///
+/// impl FilterAsync<String> for IsKnownUser {
+///     fn filter<'a>(&'a self, name: &'a String) -> BoxFuture<'a, bool> {
+///         async move { self.users.contains(name) }.boxed()
+///     }
+/// }
+///
+/// let f = IsKnownUser { users: vec![String::from("root")] };
+/// assert!(futures::executor::block_on(f.filter(&String::from("root"))));
+/// ```
+pub trait FilterAsync<N> {
+    /// The function which is used to filter something
+    fn filter<'a>(&'a self, _: &'a N) -> BoxFuture<'a, bool>;
+
+    /// Helper to invert a filter.
+    ///
+    /// ```
+    /// use filters::asynchronous::filter::FilterAsync;
+    ///
+    /// let f = (|&a: &usize| async move { a == 1 }).not();
+    ///
+    /// assert!(futures::executor::block_on(f.filter(&2)));
+    /// ```
+    fn not(self) -> AsyncNot<Self>
+    where
+        Self: Sized,
+    {
+        AsyncNot::new(self)
+    }
+
+    /// Helper to connect two filters via logical OR
+    ///
+    /// The second filter is only awaited if the first one does not pass.
+    ///
+    /// ```
+    /// use filters::asynchronous::filter::FilterAsync;
+    ///
+    /// let a = |&a: &usize| async move { a == 1 };
+    /// let b = |&a: &usize| async move { a == 2 };
+    /// let c = a.or(b);
+    ///
+    /// assert!(futures::executor::block_on(c.filter(&1)));
+    /// assert!(futures::executor::block_on(c.filter(&2)));
+    /// assert!(!futures::executor::block_on(c.filter(&7)));
+    /// ```
+    fn or<F>(self, other: F) -> AsyncOr<Self, F>
+    where
+        Self: Sized,
+        F: FilterAsync<N>,
+    {
+        AsyncOr::new(self, other)
+    }
+
+    /// Helper to connect two filters via logical AND
+    ///
+    /// The second filter is only awaited if the first one passes.
+    ///
+    /// ```
+    /// use filters::asynchronous::filter::FilterAsync;
+    ///
+    /// let a = |&a: &usize| async move { a > 1 };
+    /// let b = |&a: &usize| async move { a < 7 };
+    /// let c = a.and(b);
+    ///
+    /// assert!(!futures::executor::block_on(c.filter(&1)));
+    /// assert!(futures::executor::block_on(c.filter(&3)));
+    /// assert!(!futures::executor::block_on(c.filter(&9)));
+    /// ```
+    fn and<F>(self, other: F) -> AsyncAnd<Self, F>
+    where
+        Self: Sized,
+        F: FilterAsync<N>,
+    {
+        AsyncAnd::new(self, other)
+    }
+}
+
+/// All closures that take a ref to something and return a future of a bool are filters
+///
+/// The future must not borrow the input, so the closure has to copy what it needs from the input
+/// into the future (e.g. with `async move`). Filters which need to borrow the input can
+/// implement `FilterAsync` directly.
+impl<N, T, Fut> FilterAsync<N> for T
+where
+    T: Fn(&N) -> Fut,
+    Fut: Future<Output = bool> + Send + 'static,
+{
+    fn filter<'a>(&'a self, e: &'a N) -> BoxFuture<'a, bool> {
+        self(e).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures::executor::block_on;
+
+    use super::*;
+
+    #[test]
+    fn closure() {
+        let f = |&a: &usize| async move { a > 5 };
+
+        assert!(!block_on(f.filter(&3)));
+        assert!(block_on(f.filter(&7)));
+    }
+
+    #[test]
+    fn combinators() {
+        let f = (|&a: &usize| async move { a > 5 })
+            .and(|&a: &usize| async move { a < 15 })
+            .or(|&a: &usize| async move { a == 20 })
+            .not();
+
+        let v: Vec<usize> = (0..25).filter(|a| !block_on(f.filter(a))).collect();
+        assert_eq!(v, vec![6, 7, 8, 9, 10, 11, 12, 13, 14, 20]);
+    }
+
+    #[test]
+    fn short_circuits() {
+        let calls = AtomicUsize::new(0);
+        let counted = |&a: &usize| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async move { a < 15 }
+        };
+
+        let f = (|&a: &usize| async move { a > 5 }).and(counted);
+        assert!(!block_on(f.filter(&3)));
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        assert!(block_on(f.filter(&7)));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let f = (|&a: &usize| async move { a > 5 }).or(counted);
+        assert!(block_on(f.filter(&7)));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
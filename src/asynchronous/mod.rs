@@ -0,0 +1,14 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Asynchronous filters
+//!
+//! Filters implementing `FilterAsync` decide with a future instead of returning the decision
+//! right away. They can be combined like synchronous filters and used to filter streams.
+
+pub mod filter;
+pub mod ops;
+pub mod stream;
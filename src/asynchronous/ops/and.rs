@@ -0,0 +1,36 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! AND implementation.
+//!
+//! Will be automatically included when including `asynchronous::filter::FilterAsync`, so importing
+//! this module shouldn't be necessary.
+//!
+
+use futures::future::{BoxFuture, FutureExt};
+
+use crate::asynchronous::filter::FilterAsync;
+
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct AsyncAnd<T, U>(T, U);
+
+impl<T, U> AsyncAnd<T, U> {
+    pub fn new(a: T, b: U) -> AsyncAnd<T, U> {
+        AsyncAnd(a, b)
+    }
+}
+
+impl<N, T, U> FilterAsync<N> for AsyncAnd<T, U>
+where
+    N: Sync,
+    T: FilterAsync<N> + Sync,
+    U: FilterAsync<N> + Sync,
+{
+    fn filter<'a>(&'a self, e: &'a N) -> BoxFuture<'a, bool> {
+        async move { self.0.filter(e).await && self.1.filter(e).await }.boxed()
+    }
+}
@@ -0,0 +1,34 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! NOT implementation.
+//!
+//! Will be automatically included when including `asynchronous::filter::FilterAsync`, so importing
+//! this module shouldn't be necessary.
+//!
+
+use futures::future::{BoxFuture, FutureExt};
+
+use crate::asynchronous::filter::FilterAsync;
+
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct AsyncNot<T>(T);
+
+impl<T> AsyncNot<T> {
+    pub fn new(a: T) -> AsyncNot<T> {
+        AsyncNot(a)
+    }
+}
+
+impl<N, T> FilterAsync<N> for AsyncNot<T>
+where
+    T: FilterAsync<N>,
+{
+    fn filter<'a>(&'a self, e: &'a N) -> BoxFuture<'a, bool> {
+        self.0.filter(e).map(|b| !b).boxed()
+    }
+}
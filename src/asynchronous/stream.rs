@@ -0,0 +1,67 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Filtering streams with asynchronous filters
+
+use futures::stream::{self, Stream, StreamExt};
+
+use crate::asynchronous::filter::FilterAsync;
+
+/// Filter a stream with an asynchronous filter
+///
+/// The items are filtered one after another, so the order of the stream is kept.
+///
+/// ```
+/// use futures::stream::{self, StreamExt};
+/// use filters::asynchronous::stream::filter_stream;
+///
+/// let s = filter_stream(stream::iter(0..10), |&a: &usize| async move { a % 3 == 0 });
+/// let v: Vec<usize> = futures::executor::block_on(s.collect());
+///
+/// assert_eq!(v, vec![0, 3, 6, 9]);
+/// ```
+pub fn filter_stream<S, F>(stream: S, filter: F) -> impl Stream<Item = S::Item>
+where
+    S: Stream,
+    F: FilterAsync<S::Item>,
+{
+    let state = (Box::pin(stream), filter);
+
+    stream::unfold(state, |(mut stream, filter)| async move {
+        while let Some(item) = stream.next().await {
+            if filter.filter(&item).await {
+                return Some((item, (stream, filter)));
+            }
+        }
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use futures::stream;
+
+    use super::*;
+
+    #[test]
+    fn filters_stream() {
+        let f = (|&a: &usize| async move { a > 5 }).and(|&a: &usize| async move { a % 2 == 0 });
+        let v: Vec<usize> = block_on(filter_stream(stream::iter(0..15), f).collect());
+
+        assert_eq!(v, vec![6, 8, 10, 12, 14]);
+    }
+
+    #[test]
+    fn empty_stream() {
+        let s = filter_stream(stream::iter(Vec::<usize>::new()), |_: &usize| async {
+            true
+        });
+        let v: Vec<usize> = block_on(s.collect());
+
+        assert!(v.is_empty());
+    }
+}
@@ -4,13 +4,23 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
-use std::borrow::Borrow;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+use core::borrow::Borrow;
 
 pub use crate::failable::ops::and::FailableAnd;
 pub use crate::failable::ops::bool::FailableBool;
+pub use crate::failable::ops::by_ref::FailableByRef;
+#[cfg(feature = "alloc")]
+pub use crate::failable::ops::collect::CollectErrors;
 pub use crate::failable::ops::map::{FailableMapErr, FailableMapInput};
 pub use crate::failable::ops::not::FailableNot;
 pub use crate::failable::ops::or::FailableOr;
+pub use crate::failable::ops::unwrap::{UnwrapOr, UnwrapOrElse};
 pub use crate::failable::ops::xor::FailableXOr;
 
 /// Trait for converting something into a Filter
@@ -56,6 +66,10 @@ pub trait FailableFilter<N> {
 
     /// Helper to connect two filters via logical OR
     ///
+    /// The combined filter has the error type of `self`, the error of `other` is converted into it
+    /// with `Into`. So the filter with the wider error type has to be the left one, or both have
+    /// to be converted with `map_err()` first.
+    ///
     /// ```
     /// # #[derive(Debug)]
     /// # struct ErrorStub { }
@@ -160,8 +174,125 @@ pub trait FailableFilter<N> {
         FailableNot::new(FailableOr::new(self, other))
     }
 
+    /// Helper to connect three filters via logical NOR
+    ///
+    /// ```
+    /// # #[derive(Debug)]
+    /// # struct ErrorStub { }
+    /// #
+    /// use filters::failable::filter::FailableFilter;
+    ///
+    /// let a = (|&a: &usize| -> Result<bool, ErrorStub> { Ok(a & 1 == 1) });
+    /// let b = (|&a: &usize| -> Result<bool, ErrorStub> { Ok(a & 2 == 2) });
+    /// let c = (|&a: &usize| -> Result<bool, ErrorStub> { Ok(a & 4 == 4) });
+    /// let d = a.nor3(b, c); /* !(a || b || c) */
+    ///
+    /// // every combination of a, b and c is encoded in the bits of 0..8
+    /// assert!(d.filter(&0).unwrap());
+    /// for i in 1..8 {
+    ///     assert!(!d.filter(&i).unwrap());
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn nor3<F, F2>(
+        self,
+        other: F,
+        other2: F2,
+    ) -> FailableNot<FailableOr<Self, FailableOr<F::IntoFilt, F2::IntoFilt>>>
+    where
+        Self: Sized,
+        F: IntoFailableFilter<N> + Sized,
+        F2: IntoFailableFilter<N> + Sized,
+    {
+        FailableNot::new(self.or3(other, other2))
+    }
+
+    /// Helper to connect two filters via logical implication
+    ///
+    /// ```
+    /// # #[derive(Debug)]
+    /// # struct ErrorStub { }
+    /// #
+    /// use filters::failable::filter::FailableFilter;
+    ///
+    /// let a = (|&a: &usize| -> Result<bool, ErrorStub> { Ok(a > 10) });
+    /// let b = (|&a: &usize| -> Result<bool, ErrorStub> { Ok(a % 2 == 0) });
+    /// let c = a.implies(b); /* a > 10 => a is even */
+    ///
+    /// assert!(c.filter(&3).unwrap());
+    /// assert!(c.filter(&4).unwrap());
+    /// assert!(c.filter(&12).unwrap());
+    /// assert!(!c.filter(&13).unwrap());
+    /// ```
+    fn implies<F>(self, other: F) -> FailableOr<FailableNot<Self>, F::IntoFilt>
+    where
+        Self: Sized,
+        F: IntoFailableFilter<N> + Sized,
+    {
+        FailableOr::new(FailableNot::new(self), other.into_failable_filter())
+    }
+
+    /// Helper to connect two filters via logical non-implication
+    ///
+    /// The resulting filter passes whenever `self` passes and `other` does not pass. This is the
+    /// same as `and_not()`.
+    ///
+    /// ```
+    /// use filters::failable::filter::FailableFilter;
+    ///
+    /// for &(a, b, result) in &[
+    ///     (false, false, false),
+    ///     (false, true, false),
+    ///     (true, false, true),
+    ///     (true, true, false),
+    /// ] {
+    ///     let a = move |_: &()| -> Result<bool, ()> { Ok(a) };
+    ///     let b = move |_: &()| -> Result<bool, ()> { Ok(b) };
+    ///     let f = a.nimply(b);
+    ///     assert_eq!(f.filter(&()), Ok(result));
+    /// }
+    /// ```
+    fn nimply<F>(self, other: F) -> FailableAnd<Self, FailableNot<F::IntoFilt>>
+    where
+        Self: Sized,
+        F: IntoFailableFilter<N> + Sized,
+    {
+        self.and_not(other)
+    }
+
+    /// Helper to connect two filters via logical converse non-implication
+    ///
+    /// The resulting filter passes whenever `self` does not pass and `other` passes.
+    ///
+    /// ```
+    /// use filters::failable::filter::FailableFilter;
+    ///
+    /// for &(a, b, result) in &[
+    ///     (false, false, false),
+    ///     (false, true, true),
+    ///     (true, false, false),
+    ///     (true, true, false),
+    /// ] {
+    ///     let a = move |_: &()| -> Result<bool, ()> { Ok(a) };
+    ///     let b = move |_: &()| -> Result<bool, ()> { Ok(b) };
+    ///     let f = a.converse_nimply(b);
+    ///     assert_eq!(f.filter(&()), Ok(result));
+    /// }
+    /// ```
+    fn converse_nimply<F>(self, other: F) -> FailableAnd<FailableNot<Self>, F::IntoFilt>
+    where
+        Self: Sized,
+        F: IntoFailableFilter<N> + Sized,
+    {
+        FailableAnd::new(FailableNot::new(self), other.into_failable_filter())
+    }
+
     /// Helper to connect two filters via logical XOR
     ///
+    /// The combined filter has the error type of `self`, the error of `other` is converted into it
+    /// with `Into`. So the filter with the wider error type has to be the left one, or both have
+    /// to be converted with `map_err()` first.
+    ///
     /// ```
     /// # #[derive(Debug)]
     /// # struct ErrorStub { }
@@ -185,8 +316,45 @@ pub trait FailableFilter<N> {
         FailableXOr::new(self, other)
     }
 
+    /// Helper to connect three filters via logical XOR
+    ///
+    /// This is the parity of the three filters: it passes if an odd number of them passes, so
+    /// also if all three pass. It does not check whether exactly one filter passes.
+    ///
+    /// ```
+    /// # #[derive(Debug)]
+    /// # struct ErrorStub { }
+    /// #
+    /// use filters::failable::filter::FailableFilter;
+    ///
+    /// let a = (|&a: &usize| -> Result<bool, ErrorStub> { Ok(a & 1 == 1) });
+    /// let b = (|&a: &usize| -> Result<bool, ErrorStub> { Ok(a & 2 == 2) });
+    /// let c = (|&a: &usize| -> Result<bool, ErrorStub> { Ok(a & 4 == 4) });
+    /// let d = a.xor3(b, c);
+    ///
+    /// // every combination of a, b and c is encoded in the bits of 0..8
+    /// assert!(!d.filter(&0).unwrap()); // none
+    /// assert!(d.filter(&1).unwrap());  // a
+    /// assert!(d.filter(&2).unwrap());  // b
+    /// assert!(!d.filter(&3).unwrap()); // a, b
+    /// assert!(d.filter(&4).unwrap());  // c
+    /// assert!(!d.filter(&5).unwrap()); // a, c
+    /// assert!(!d.filter(&6).unwrap()); // b, c
+    /// assert!(d.filter(&7).unwrap());  // a, b, c
+    /// ```
+    fn xor3<F, F2>(self, other: F, other2: F2) -> FailableXOr<Self, FailableXOr<F, F2>>
+    where
+        Self: Sized,
+    {
+        FailableXOr::new(self, FailableXOr::new(other, other2))
+    }
+
     /// Helper to connect two filters via logical AND
     ///
+    /// The combined filter has the error type of `self`, the error of `other` is converted into it
+    /// with `Into`. So the filter with the wider error type has to be the left one, or both have
+    /// to be converted with `map_err()` first.
+    ///
     /// ```
     /// # #[derive(Debug)]
     /// # struct ErrorStub { }
@@ -203,6 +371,18 @@ pub trait FailableFilter<N> {
     /// assert!(c.filter(&6).unwrap());
     /// assert!(!c.filter(&9).unwrap());
     /// ```
+    ///
+    /// Only the error of the right filter is converted:
+    ///
+    /// ```compile_fail
+    /// use filters::failable::filter::FailableFilter;
+    ///
+    /// let narrow = |&a: &usize| -> Result<bool, std::num::TryFromIntError> { Ok(a > 1) };
+    /// let wide = |&a: &usize| -> Result<bool, Box<dyn std::error::Error>> { Ok(a < 7) };
+    ///
+    /// let c = narrow.and(wide);
+    /// c.filter(&3).unwrap();
+    /// ```
     fn and<F>(self, other: F) -> FailableAnd<Self, F::IntoFilt>
     where
         Self: Sized,
@@ -303,6 +483,84 @@ pub trait FailableFilter<N> {
         FailableNot::new(FailableAnd::new(self, other))
     }
 
+    /// Helper to connect three filters via logical NAND
+    ///
+    /// ```
+    /// # #[derive(Debug)]
+    /// # struct ErrorStub { }
+    /// #
+    /// use filters::failable::filter::FailableFilter;
+    ///
+    /// let a = (|&a: &usize| -> Result<bool, ErrorStub> { Ok(a & 1 == 1) });
+    /// let b = (|&a: &usize| -> Result<bool, ErrorStub> { Ok(a & 2 == 2) });
+    /// let c = (|&a: &usize| -> Result<bool, ErrorStub> { Ok(a & 4 == 4) });
+    /// let d = a.nand3(b, c); /* !(a && b && c) */
+    ///
+    /// // every combination of a, b and c is encoded in the bits of 0..8
+    /// for i in 0..7 {
+    ///     assert!(d.filter(&i).unwrap());
+    /// }
+    /// assert!(!d.filter(&7).unwrap());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn nand3<F, F2>(
+        self,
+        other: F,
+        other2: F2,
+    ) -> FailableNot<FailableAnd<Self, FailableAnd<F::IntoFilt, F2::IntoFilt>>>
+    where
+        Self: Sized,
+        F: IntoFailableFilter<N> + Sized,
+        F2: IntoFailableFilter<N> + Sized,
+    {
+        FailableNot::new(self.and3(other, other2))
+    }
+
+    /// Helper to borrow a filter, so it can be reused after being passed somewhere
+    ///
+    /// ```
+    /// # #[derive(Debug)]
+    /// # struct ErrorStub { }
+    /// #
+    /// use filters::failable::filter::FailableFilter;
+    ///
+    /// let a = (|&a: &usize| -> Result<bool, ErrorStub> { Ok(a > 5) });
+    /// let b = a.by_ref().and(|&a: &usize| -> Result<bool, ErrorStub> { Ok(a < 10) });
+    ///
+    /// assert!(b.filter(&7).unwrap());
+    /// assert!(a.filter(&12).unwrap());
+    /// ```
+    fn by_ref(&self) -> FailableByRef<'_, Self>
+    where
+        Self: Sized,
+    {
+        FailableByRef::new(self)
+    }
+
+    /// Helper to box a failable filter into a trait object
+    ///
+    /// ```
+    /// # #[derive(Debug)]
+    /// # struct ErrorStub { }
+    /// #
+    /// use filters::failable::filter::{BoxedFailableFilter, FailableFilter};
+    ///
+    /// let filters: Vec<BoxedFailableFilter<usize, ErrorStub>> = vec![
+    ///     (|&a: &usize| -> Result<bool, ErrorStub> { Ok(a > 5) }).boxed(),
+    ///     (|&a: &usize| -> Result<bool, ErrorStub> { Ok(a < 10) }).not().boxed(),
+    /// ];
+    ///
+    /// assert!(filters[0].filter(&7).unwrap());
+    /// assert!(!filters[1].filter(&7).unwrap());
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn boxed(self) -> BoxedFailableFilter<N, Self::Error>
+    where
+        Self: Sized + 'static,
+    {
+        Box::new(self)
+    }
+
     /// Helper to transform the input of a filter
     ///
     /// ```
@@ -358,6 +616,100 @@ pub trait FailableFilter<N> {
     {
         FailableMapErr::new(self, map)
     }
+
+    /// Helper to convert the error of a filter with `Into`
+    ///
+    /// This is a shorthand for `map_err(Into::into)`.
+    ///
+    /// ```
+    /// use std::fmt;
+    /// use filters::failable::filter::FailableFilter;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct MyError(String);
+    ///
+    /// impl From<fmt::Error> for MyError {
+    ///     fn from(_: fmt::Error) -> MyError {
+    ///         MyError(String::from("formatting failed"))
+    ///     }
+    /// }
+    ///
+    /// let a = (|&a: &usize| -> Result<bool, MyError> { Ok(a > 1) });
+    /// let b = (|&a: &usize| -> Result<bool, fmt::Error> {
+    ///     if a == 0 { Err(fmt::Error) } else { Ok(a < 7) }
+    /// });
+    /// let c = a.or(b.err_into::<MyError>());
+    ///
+    /// assert_eq!(c.filter(&3), Ok(true));
+    /// assert_eq!(c.filter(&0), Err(MyError(String::from("formatting failed"))));
+    /// ```
+    fn err_into<OE>(self) -> FailableMapErr<Self, fn(Self::Error) -> OE, OE>
+    where
+        Self: Sized,
+        Self::Error: Into<OE>,
+    {
+        let map: fn(Self::Error) -> OE = Into::into;
+        self.map_err(map)
+    }
+
+    /// Helper to transform a failable filter into a Filter, which returns `default` on error
+    ///
+    /// ```
+    /// # #[derive(Debug)]
+    /// # struct ErrorStub { }
+    /// #
+    /// use filters::failable::filter::FailableFilter;
+    /// use filters::filter::Filter;
+    ///
+    /// let a = |&a: &usize| -> Result<bool, ErrorStub> {
+    ///     if a == 0 {
+    ///         Err(ErrorStub {})
+    ///     } else {
+    ///         Ok(a > 5)
+    ///     }
+    /// };
+    /// let b = a.unwrap_or(false);
+    ///
+    /// let r: Vec<usize> = vec![0, 3, 7].into_iter().filter(|x| b.filter(x)).collect();
+    /// assert_eq!(r, vec![7]);
+    /// ```
+    fn unwrap_or(self, default: bool) -> UnwrapOr<Self>
+    where
+        Self: Sized,
+    {
+        UnwrapOr::new(self, default)
+    }
+
+    /// Helper to transform a failable filter into a Filter, which computes the decision from the
+    /// error on error
+    ///
+    /// ```
+    /// # #[derive(Debug)]
+    /// enum LookupError { NotFound, Timeout }
+    ///
+    /// use filters::failable::filter::FailableFilter;
+    /// use filters::filter::Filter;
+    ///
+    /// let a = |&a: &usize| -> Result<bool, LookupError> {
+    ///     match a {
+    ///         0 => Err(LookupError::NotFound),
+    ///         1 => Err(LookupError::Timeout),
+    ///         _ => Ok(a > 5),
+    ///     }
+    /// };
+    /// let b = a.unwrap_or_else(|e| matches!(e, LookupError::Timeout));
+    ///
+    /// assert!(!b.filter(&0));
+    /// assert!(b.filter(&1));
+    /// assert!(b.filter(&7));
+    /// ```
+    fn unwrap_or_else<G>(self, g: G) -> UnwrapOrElse<Self, G>
+    where
+        Self: Sized,
+        G: Fn(&Self::Error) -> bool,
+    {
+        UnwrapOrElse::new(self, g)
+    }
 }
 
 /// All closures that take a ref to something and return Result<bool, E> are failable filters
@@ -372,6 +724,52 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
+/// A boxed failable filter trait object
+pub type BoxedFailableFilter<N, E> = Box<dyn FailableFilter<N, Error = E>>;
+
+// A blanket impl for `Box<F>` would overlap with the one for closures, as boxed closures are
+// closures themselves. So the trait objects are covered one by one instead.
+macro_rules! impl_failable_filter_for_box {
+    ($( $bound:ident )*) => {
+        #[cfg(feature = "alloc")]
+        /// Boxed failable filters are failable filters, so they can be stored in collections and
+        /// combined
+        impl<'a, N, E> FailableFilter<N> for Box<dyn FailableFilter<N, Error = E> $( + $bound )* + 'a> {
+            type Error = E;
+
+            fn filter(&self, e: &N) -> Result<bool, Self::Error> {
+                (**self).filter(e)
+            }
+        }
+    };
+}
+
+impl_failable_filter_for_box!();
+impl_failable_filter_for_box!(Send);
+impl_failable_filter_for_box!(Sync);
+impl_failable_filter_for_box!(Send Sync);
+
+#[cfg(feature = "alloc")]
+/// Failable filters shared via `Rc` are failable filters
+impl<N, F: FailableFilter<N> + ?Sized> FailableFilter<N> for Rc<F> {
+    type Error = F::Error;
+
+    fn filter(&self, e: &N) -> Result<bool, Self::Error> {
+        (**self).filter(e)
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Failable filters shared via `Arc` are failable filters
+impl<N, F: FailableFilter<N> + ?Sized> FailableFilter<N> for Arc<F> {
+    type Error = F::Error;
+
+    fn filter(&self, e: &N) -> Result<bool, Self::Error> {
+        (**self).filter(e)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -386,6 +784,13 @@ mod tests {
         assert!(a.filter(&1).unwrap());
     }
 
+    #[test]
+    fn test_failable_bool_eq_and_default() {
+        assert_eq!(FailableBool::new(true), FailableBool::from(true));
+        assert_eq!(FailableBool::default(), FailableBool::new(false));
+        assert_eq!(FailableBool::default().filter(&0), Ok(false));
+    }
+
     #[test]
     fn test_error_return() {
         let a = |_: &i32| -> Result<bool, StupError> { Err(StupError {}) };
@@ -417,6 +822,291 @@ mod tests {
         assert!(e.filter(&1).is_err());
     }
 
+    #[test]
+    fn test_or() {
+        let a = |&x: &i32| -> Result<bool, StupError> { Ok(x < 3) };
+        let b = |&x: &i32| -> Result<bool, StupError> { Ok(x > 7) };
+        let c = a.or(b);
+
+        assert!(c.filter(&1).unwrap());
+        assert!(!c.filter(&5).unwrap());
+        assert!(c.filter(&9).unwrap());
+    }
+
+    #[test]
+    fn test_or_short_circuits_error() {
+        let a = |&x: &i32| -> Result<bool, StupError> { Ok(x < 3) };
+        let b = |_: &i32| -> Result<bool, StupError> { Err(StupError {}) };
+        let c = a.or(b);
+
+        assert!(c.filter(&1).unwrap());
+        assert!(c.filter(&5).is_err());
+    }
+
+    #[test]
+    fn test_and_short_circuits() {
+        let a = |&x: &i32| -> Result<bool, StupError> { Ok(x > 3) };
+        let b = |_: &i32| -> Result<bool, StupError> { panic!("must not be evaluated") };
+        let c = a.and(b);
+
+        assert!(!c.filter(&1).unwrap());
+    }
+
+    #[test]
+    fn test_or_short_circuits() {
+        let a = |&x: &i32| -> Result<bool, StupError> { Ok(x > 3) };
+        let b = |_: &i32| -> Result<bool, StupError> { panic!("must not be evaluated") };
+        let c = a.or(b);
+
+        assert!(c.filter(&5).unwrap());
+    }
+
+    #[test]
+    fn test_left_error_short_circuits() {
+        let a = |_: &i32| -> Result<bool, StupError> { Err(StupError {}) };
+        let b = |_: &i32| -> Result<bool, StupError> { panic!("must not be evaluated") };
+
+        assert!(a.and(b).filter(&1).is_err());
+        assert!(a.or(b).filter(&1).is_err());
+    }
+
+    #[test]
+    fn test_unwrap_or() {
+        use crate::filter::Filter;
+
+        let a = |&x: &i32| -> Result<bool, StupError> {
+            if x < 0 {
+                Err(StupError {})
+            } else {
+                Ok(x > 5)
+            }
+        };
+
+        let f = a.unwrap_or(true);
+        assert!(f.filter(&-1));
+        assert!(!f.filter(&3));
+        assert!(f.filter(&7));
+
+        let f = a.unwrap_or(false);
+        assert!(!f.filter(&-1));
+        assert!(f.filter(&7));
+    }
+
+    #[test]
+    fn test_unwrap_or_else() {
+        use crate::filter::Filter;
+
+        #[derive(Debug)]
+        enum LookupError {
+            NotFound,
+            Timeout,
+        }
+
+        let a = |&x: &i32| -> Result<bool, LookupError> {
+            match x {
+                -1 => Err(LookupError::NotFound),
+                -2 => Err(LookupError::Timeout),
+                _ => Ok(x > 5),
+            }
+        };
+
+        let f = a.unwrap_or_else(|e| match e {
+            LookupError::NotFound => false,
+            LookupError::Timeout => true,
+        });
+
+        assert!(!f.filter(&-1));
+        assert!(f.filter(&-2));
+        assert!(!f.filter(&3));
+        assert!(f.filter(&7));
+    }
+
+    #[test]
+    fn test_heterogeneous_errors() {
+        use std::io;
+
+        #[derive(Debug)]
+        struct RuleError;
+
+        #[derive(Debug)]
+        enum ValidationError {
+            Io(io::Error),
+            Rule(RuleError),
+        }
+
+        impl From<io::Error> for ValidationError {
+            fn from(e: io::Error) -> ValidationError {
+                ValidationError::Io(e)
+            }
+        }
+
+        impl From<RuleError> for ValidationError {
+            fn from(e: RuleError) -> ValidationError {
+                ValidationError::Rule(e)
+            }
+        }
+
+        let readable = |&x: &i32| -> Result<bool, io::Error> {
+            if x < 0 {
+                Err(io::Error::from(io::ErrorKind::NotFound))
+            } else {
+                Ok(true)
+            }
+        };
+        let rule = |&x: &i32| -> Result<bool, RuleError> {
+            if x > 100 {
+                Err(RuleError)
+            } else {
+                Ok(x > 5)
+            }
+        };
+        let shared = |_: &i32| -> Result<bool, ValidationError> { Ok(true) };
+
+        let f = shared.and(readable).and(rule);
+        assert!(f.filter(&7).unwrap());
+        assert!(!f.filter(&3).unwrap());
+        assert!(matches!(
+            f.filter(&-1),
+            Err(ValidationError::Io(e)) if e.kind() == io::ErrorKind::NotFound
+        ));
+        assert!(matches!(f.filter(&101), Err(ValidationError::Rule(_))));
+
+        let f = readable.map_err(ValidationError::from).or(rule).xor(shared);
+        assert!(!f.filter(&7).unwrap());
+        assert!(matches!(
+            f.filter(&-1),
+            Err(ValidationError::Io(e)) if e.kind() == io::ErrorKind::NotFound
+        ));
+    }
+
+    #[test]
+    fn test_right_error_converted() {
+        use std::num::ParseIntError;
+
+        #[derive(Debug, PartialEq)]
+        enum ConfigError {
+            Missing,
+            Parse(ParseIntError),
+        }
+
+        impl From<ParseIntError> for ConfigError {
+            fn from(e: ParseIntError) -> ConfigError {
+                ConfigError::Parse(e)
+            }
+        }
+
+        let present = |s: &&str| -> Result<bool, ConfigError> {
+            if s.is_empty() {
+                Err(ConfigError::Missing)
+            } else {
+                Ok(true)
+            }
+        };
+        let small = |s: &&str| -> Result<bool, ParseIntError> { s.parse::<u8>().map(|n| n < 10) };
+
+        let f = present.and(small);
+        assert_eq!(f.filter(&"3"), Ok(true));
+        assert_eq!(f.filter(&"30"), Ok(false));
+        assert_eq!(f.filter(&""), Err(ConfigError::Missing));
+        assert!(matches!(f.filter(&"x"), Err(ConfigError::Parse(_))));
+
+        let f = present.not().or(small);
+        assert_eq!(f.filter(&"30"), Ok(false));
+        assert!(matches!(f.filter(&"x"), Err(ConfigError::Parse(_))));
+
+        let f = present.xor(small);
+        assert_eq!(f.filter(&"30"), Ok(true));
+        assert!(matches!(f.filter(&"x"), Err(ConfigError::Parse(_))));
+
+        // With the operands reversed, the narrow error has to be converted explicitly
+        let f = small.map_err(ConfigError::from).and(present);
+        assert_eq!(f.filter(&"3"), Ok(true));
+        assert!(matches!(f.filter(&""), Err(ConfigError::Parse(_))));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_boxed_filters() {
+        let a = |&x: &i32| -> Result<bool, StupError> { Ok(x > 5) };
+        let b = |&x: &i32| -> Result<bool, StupError> {
+            if x < 0 {
+                Err(StupError {})
+            } else {
+                Ok(x % 2 == 0)
+            }
+        };
+
+        let filters: Vec<Box<dyn FailableFilter<i32, Error = StupError>>> =
+            vec![Box::new(a), b.boxed()];
+
+        assert!(filters[0].filter(&7).unwrap());
+        assert!(!filters[1].filter(&7).unwrap());
+        assert!(filters[1].filter(&-1).is_err());
+
+        let c = filters.into_iter().next().unwrap().and(a);
+        assert!(c.filter(&7).unwrap());
+        assert!(!c.filter(&3).unwrap());
+    }
+
+    #[test]
+    fn test_by_ref() {
+        struct LessThan(i32);
+        impl FailableFilter<i32> for LessThan {
+            type Error = StupError;
+
+            fn filter(&self, e: &i32) -> Result<bool, StupError> {
+                Ok(*e < self.0)
+            }
+        }
+
+        let lt = LessThan(5);
+        let a = lt
+            .by_ref()
+            .and(|_: &i32| -> Result<bool, StupError> { Ok(true) });
+        let b = lt.by_ref().not();
+
+        assert!(a.filter(&1).unwrap());
+        assert!(!b.filter(&1).unwrap());
+        assert!(!lt.filter(&7).unwrap());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_boxed_trait_objects() {
+        let limit = 1;
+        let borrowing: Box<dyn FailableFilter<i32, Error = StupError> + '_> =
+            Box::new(|x: &i32| -> Result<bool, StupError> { Ok(*x > limit) });
+        let sendable: Box<dyn FailableFilter<i32, Error = StupError> + Send + Sync> =
+            Box::new(|_: &i32| -> Result<bool, StupError> { Err(StupError {}) });
+
+        assert!(borrowing.filter(&2).unwrap());
+        assert!(borrowing.and(sendable).filter(&2).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_rc_and_arc() {
+        let a: Rc<dyn FailableFilter<i32, Error = StupError>> =
+            Rc::new(|x: &i32| -> Result<bool, StupError> { Ok(*x > 1) });
+        let b = Arc::new(|_: &i32| -> Result<bool, StupError> { Err(StupError {}) });
+
+        assert!(a.filter(&2).unwrap());
+        assert!(Rc::clone(&a).and(Arc::clone(&b)).filter(&2).is_err());
+        assert!(!a.and(b).filter(&1).unwrap());
+    }
+
+    #[test]
+    fn test_debug_output() {
+        let a = FailableAnd::new(
+            FailableBool::new(true),
+            FailableNot::new(FailableBool::new(false)),
+        );
+        assert_eq!(
+            format!("{:?}", a),
+            "FailableAnd(FailableBool(true), FailableNot(FailableBool(false)))"
+        );
+    }
+
     #[test]
     fn test_both_filter_types() {
         use crate::filter::Filter;
@@ -12,8 +12,13 @@
 
 use crate::failable::filter::FailableFilter;
 
+/// Failable filter which passes if both filters pass
+///
+/// The filters are evaluated from left to right, and the right filter is only evaluated if the
+/// left filter passes. If the left filter fails, its error is returned without evaluating the
+/// right filter.
 #[must_use = "filters are lazy and do nothing unless consumed"]
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct FailableAnd<T, U>(T, U);
 
 impl<T, U> FailableAnd<T, U> {
@@ -22,14 +27,16 @@ impl<T, U> FailableAnd<T, U> {
     }
 }
 
-impl<N, T, U, E> FailableFilter<N> for FailableAnd<T, U>
+/// The error of the right filter is converted into the error of the left filter
+impl<N, T, U> FailableFilter<N> for FailableAnd<T, U>
 where
-    T: FailableFilter<N, Error = E>,
-    U: FailableFilter<N, Error = E>,
+    T: FailableFilter<N>,
+    U: FailableFilter<N>,
+    U::Error: Into<T::Error>,
 {
-    type Error = E;
+    type Error = T::Error;
 
     fn filter(&self, e: &N) -> Result<bool, Self::Error> {
-        Ok(self.0.filter(e)? && self.1.filter(e)?)
+        Ok(self.0.filter(e)? && self.1.filter(e).map_err(Into::into)?)
     }
 }
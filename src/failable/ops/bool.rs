@@ -13,7 +13,7 @@
 use crate::failable::filter::FailableFilter;
 
 #[must_use = "filters are lazy and do nothing unless consumed"]
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct FailableBool(bool);
 
 impl FailableBool {
@@ -22,6 +22,13 @@ impl FailableBool {
     }
 }
 
+/// The default `FailableBool` rejects everything
+impl Default for FailableBool {
+    fn default() -> FailableBool {
+        FailableBool::new(false)
+    }
+}
+
 impl From<bool> for FailableBool {
     fn from(b: bool) -> FailableBool {
         FailableBool::new(b)
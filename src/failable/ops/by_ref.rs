@@ -0,0 +1,34 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Borrowing filter implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+
+use crate::failable::filter::FailableFilter;
+
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct FailableByRef<'a, F: 'a + ?Sized>(&'a F);
+
+impl<'a, F: 'a + ?Sized> FailableByRef<'a, F> {
+    pub fn new(a: &'a F) -> FailableByRef<'a, F> {
+        FailableByRef(a)
+    }
+}
+
+impl<'a, F, N> FailableFilter<N> for FailableByRef<'a, F>
+where
+    F: FailableFilter<N> + 'a + ?Sized,
+{
+    type Error = F::Error;
+
+    fn filter(&self, e: &N) -> Result<bool, Self::Error> {
+        self.0.filter(e)
+    }
+}
@@ -0,0 +1,108 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Error collecting implementation.
+//!
+//! Will be automatically included when including `failable::filter::FailableFilter`, so
+//! importing this module shouldn't be necessary.
+//!
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::failable::filter::FailableFilter;
+
+/// Failable filter which passes if all of a runtime collection of filters pass, and reports the
+/// errors of all filters instead of only the first one
+///
+/// Every filter is run, even if an earlier one failed or errored. If any filter errored, all
+/// errors are returned in the order of the filters. An empty `CollectErrors` always passes.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+pub struct CollectErrors<N, E>(Vec<Box<dyn FailableFilter<N, Error = E>>>);
+
+impl<N, E> CollectErrors<N, E> {
+    pub fn new(filters: Vec<Box<dyn FailableFilter<N, Error = E>>>) -> CollectErrors<N, E> {
+        CollectErrors(filters)
+    }
+}
+
+impl_boxed_debug!(CollectErrors, N, E; filters: 0);
+
+impl<N, E> FailableFilter<N> for CollectErrors<N, E> {
+    type Error = Vec<E>;
+
+    fn filter(&self, e: &N) -> Result<bool, Self::Error> {
+        let mut passed = true;
+        let mut errors = Vec::new();
+
+        for f in self.0.iter() {
+            match f.filter(e) {
+                Ok(b) => passed &= b,
+                Err(err) => errors.push(err),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(passed)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum RuleError {
+        TooShort,
+        NoDigit,
+    }
+
+    fn rules() -> CollectErrors<&'static str, RuleError> {
+        CollectErrors::new(vec![
+            Box::new(|s: &&str| -> Result<bool, RuleError> {
+                if s.len() < 8 {
+                    Err(RuleError::TooShort)
+                } else {
+                    Ok(true)
+                }
+            }),
+            Box::new(|s: &&str| -> Result<bool, RuleError> {
+                if s.chars().any(|c| c.is_ascii_digit()) {
+                    Ok(true)
+                } else {
+                    Err(RuleError::NoDigit)
+                }
+            }),
+            Box::new(|s: &&str| -> Result<bool, RuleError> { Ok(!s.contains(' ')) }),
+        ])
+    }
+
+    #[test]
+    fn all_errors_are_reported() {
+        assert_eq!(
+            rules().filter(&"short"),
+            Err(vec![RuleError::TooShort, RuleError::NoDigit])
+        );
+        assert_eq!(
+            rules().filter(&"no digits here"),
+            Err(vec![RuleError::NoDigit])
+        );
+    }
+
+    #[test]
+    fn decisions_are_anded() {
+        assert_eq!(rules().filter(&"passw0rd"), Ok(true));
+        assert_eq!(rules().filter(&"pass w0rd"), Ok(false));
+    }
+
+    #[test]
+    fn empty_passes() {
+        let f: CollectErrors<usize, ()> = CollectErrors::new(Vec::new());
+        assert_eq!(f.filter(&0), Ok(true));
+    }
+}
@@ -9,13 +9,13 @@
 //! Will be automatically included when including `filter::Filter`, so importing this module
 //! shouldn't be necessary.
 //!
-use std::borrow::Borrow;
-use std::marker::PhantomData;
+use core::borrow::Borrow;
+use core::fmt;
+use core::marker::PhantomData;
 
 use crate::failable::filter::FailableFilter;
 
 #[must_use = "filters are lazy and do nothing unless consumed"]
-#[derive(Clone)]
 pub struct FailableMapInput<F, M, FT, B>(F, M, PhantomData<FT>, PhantomData<B>);
 
 impl<F, M, FT, B> FailableMapInput<F, M, FT, B> {
@@ -24,6 +24,23 @@ impl<F, M, FT, B> FailableMapInput<F, M, FT, B> {
     }
 }
 
+/// Only the filter and the closure need to be `Clone`, the marker types do not
+impl<F: Clone, M: Clone, FT, B> Clone for FailableMapInput<F, M, FT, B> {
+    fn clone(&self) -> Self {
+        FailableMapInput(self.0.clone(), self.1.clone(), PhantomData, PhantomData)
+    }
+}
+
+/// Only the filter and the closure need to be `Debug`, the marker types do not
+impl<F: fmt::Debug, M: fmt::Debug, FT, B> fmt::Debug for FailableMapInput<F, M, FT, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("FailableMapInput")
+            .field(&self.0)
+            .field(&self.1)
+            .finish()
+    }
+}
+
 impl<FT, F, T, B, M> FailableFilter<T> for FailableMapInput<F, M, FT, B>
 where
     F: FailableFilter<FT>,
@@ -38,7 +55,7 @@ where
 }
 
 #[must_use = "filters are lazy and do nothing unless consumed"]
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct FailableMapErr<F, M, E>(F, M, PhantomData<E>);
 
 impl<F, M, E> FailableMapErr<F, M, E> {
@@ -58,3 +75,38 @@ where
         self.0.filter(e).map_err(&self.1)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::failable::filter::FailableFilter;
+    use crate::failable::ops::map::FailableMapInput;
+
+    #[test]
+    fn failable_map_input_clone_without_clone_marker() {
+        struct NotClone(u8);
+
+        let a: FailableMapInput<_, _, NotClone, NotClone> = FailableMapInput::new(
+            |n: &NotClone| -> Result<bool, ()> { Ok(n.0 > 3) },
+            |&x: &u8| NotClone(x),
+        );
+        let b = a.clone();
+
+        assert_eq!(b.filter(&4), Ok(true));
+        assert_eq!(b.filter(&2), Ok(false));
+    }
+
+    #[test]
+    fn failable_map_input_debug_without_debug_marker() {
+        use crate::failable::ops::bool::FailableBool;
+
+        struct NotDebug;
+
+        let a: FailableMapInput<FailableBool, FailableBool, NotDebug, NotDebug> =
+            FailableMapInput::new(FailableBool::new(true), FailableBool::new(false));
+
+        assert_eq!(
+            format!("{:?}", a),
+            "FailableMapInput(FailableBool(true), FailableBool(false))"
+        );
+    }
+}
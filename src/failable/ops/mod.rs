@@ -6,7 +6,11 @@
 
 pub mod and;
 pub mod bool;
+pub mod by_ref;
+#[cfg(feature = "alloc")]
+pub mod collect;
 pub mod map;
 pub mod not;
 pub mod or;
+pub mod unwrap;
 pub mod xor;
@@ -13,7 +13,7 @@
 use crate::failable::filter::FailableFilter;
 
 #[must_use = "filters are lazy and do nothing unless consumed"]
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct FailableNot<T>(T);
 
 impl<T> FailableNot<T> {
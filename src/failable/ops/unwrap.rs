@@ -0,0 +1,55 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Unwrapping implementation, to turn a FailableFilter into a Filter.
+//!
+//! Will be automatically included when including `failable::filter::FailableFilter`, so
+//! importing this module shouldn't be necessary.
+//!
+
+use crate::failable::filter::FailableFilter;
+use crate::filter::Filter;
+
+/// Filter which returns a default decision if the inner failable filter errors
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct UnwrapOr<T>(T, bool);
+
+impl<T> UnwrapOr<T> {
+    pub fn new(a: T, default: bool) -> UnwrapOr<T> {
+        UnwrapOr(a, default)
+    }
+}
+
+impl<N, T> Filter<N> for UnwrapOr<T>
+where
+    T: FailableFilter<N>,
+{
+    fn filter(&self, e: &N) -> bool {
+        self.0.filter(e).unwrap_or(self.1)
+    }
+}
+
+/// Filter which computes the decision from the error if the inner failable filter errors
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct UnwrapOrElse<T, G>(T, G);
+
+impl<T, G> UnwrapOrElse<T, G> {
+    pub fn new(a: T, g: G) -> UnwrapOrElse<T, G> {
+        UnwrapOrElse(a, g)
+    }
+}
+
+impl<N, T, G> Filter<N> for UnwrapOrElse<T, G>
+where
+    T: FailableFilter<N>,
+    G: Fn(&T::Error) -> bool,
+{
+    fn filter(&self, e: &N) -> bool {
+        self.0.filter(e).unwrap_or_else(|err| (self.1)(&err))
+    }
+}
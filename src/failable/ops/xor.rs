@@ -13,7 +13,7 @@
 use crate::failable::filter::FailableFilter;
 
 #[must_use = "filters are lazy and do nothing unless consumed"]
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct FailableXOr<T, U>(T, U);
 
 impl<T, U> FailableXOr<T, U> {
@@ -22,14 +22,16 @@ impl<T, U> FailableXOr<T, U> {
     }
 }
 
-impl<N, T, U, E> FailableFilter<N> for FailableXOr<T, U>
+/// The error of the right filter is converted into the error of the left filter
+impl<N, T, U> FailableFilter<N> for FailableXOr<T, U>
 where
-    T: FailableFilter<N, Error = E>,
-    U: FailableFilter<N, Error = E>,
+    T: FailableFilter<N>,
+    U: FailableFilter<N>,
+    U::Error: Into<T::Error>,
 {
-    type Error = E;
+    type Error = T::Error;
 
     fn filter(&self, e: &N) -> Result<bool, Self::Error> {
-        Ok(self.0.filter(e)? ^ self.1.filter(e)?)
+        Ok(self.0.filter(e)? ^ self.1.filter(e).map_err(Into::into)?)
     }
 }
@@ -6,14 +6,84 @@
 
 //! The filter implementation
 //!
-use std::borrow::Borrow;
-
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+use core::borrow::Borrow;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::panic::RefUnwindSafe;
+
+#[cfg(feature = "alloc")]
+use crate::failable::filter::BoxedFailableFilter;
+use crate::failable::filter::FailableFilter;
+
+#[cfg(feature = "alloc")]
+pub use crate::ops::all::All;
 pub use crate::ops::and::And;
-pub use crate::ops::bool::Bool;
-pub use crate::ops::failable::{AsFailable, IntoFailable};
-pub use crate::ops::map::MapInput;
-pub use crate::ops::not::Not;
+#[cfg(feature = "alloc")]
+pub use crate::ops::and_all::AndAll;
+#[cfg(feature = "alloc")]
+pub use crate::ops::any::Any;
+pub use crate::ops::bool::{Always, Bool, Never};
+pub use crate::ops::by_ref::ByRef;
+#[cfg(feature = "std")]
+pub use crate::ops::cache::Cached;
+#[cfg(feature = "std")]
+pub use crate::ops::catch_panic::CatchPanic;
+pub use crate::ops::cmp::{Equal, GreaterEq, GreaterThan, LessEq, LessThan};
+pub use crate::ops::counting::Counting;
+#[cfg(feature = "std")]
+pub use crate::ops::debounce_time::DebounceTime;
+pub use crate::ops::dyn_range::DynRange;
+#[cfg(feature = "alloc")]
+pub use crate::ops::exactly_one::ExactlyOne;
+pub use crate::ops::failable::{AndThenFailable, AsFailable, IntoFailable};
+#[cfg(feature = "alloc")]
+pub use crate::ops::first_match::FirstMatch;
+#[cfg(feature = "std")]
+pub use crate::ops::float::WithinPercent;
+pub use crate::ops::from_fn::FromFn;
+pub use crate::ops::geo::InBoundingBox;
+pub use crate::ops::inspect::Inspect;
+pub use crate::ops::map::{MapInput, MapInput2, MapInputField};
+#[cfg(feature = "alloc")]
+pub use crate::ops::match_count::MatchCount;
+#[cfg(feature = "alloc")]
+pub use crate::ops::materialize::MaterializedFilter;
+pub use crate::ops::monotonic::{NonDecreasing, NonIncreasing};
+pub use crate::ops::not::{AsNot, Not};
+pub use crate::ops::nth::{AtomicNthMatch, NthMatch};
+pub use crate::ops::on_ref::{OnOwned, OnRef};
 pub use crate::ops::or::Or;
+#[cfg(feature = "alloc")]
+pub use crate::ops::or_all::OrAll;
+pub use crate::ops::range::InRange;
+#[cfg(feature = "std")]
+pub use crate::ops::rate_limit::RateLimit;
+pub use crate::ops::select::Select;
+#[cfg(feature = "alloc")]
+pub use crate::ops::set::AnyOf;
+#[cfg(feature = "std")]
+pub use crate::ops::set::OneOf;
+#[cfg(feature = "std")]
+pub use crate::ops::shard::Shard;
+pub use crate::ops::spy::Spy;
+#[cfg(feature = "alloc")]
+pub use crate::ops::string::{Contains, EndsWith, StartsWith};
+#[cfg(feature = "alloc")]
+pub use crate::ops::threshold::Threshold;
+pub use crate::ops::toggle::Toggle;
+pub use crate::ops::traced::Traced;
+#[cfg(feature = "std")]
+pub use crate::ops::transition::AllowedTransition;
+#[cfg(feature = "alloc")]
+pub use crate::ops::weighted::Weighted;
+pub use crate::ops::xnor::XNor;
 pub use crate::ops::xor::XOr;
 
 /// Trait for converting something into a Filter
@@ -39,6 +109,126 @@ impl<I, T: Fn(&I) -> bool> Filter<I> for T {
     }
 }
 
+#[cfg(feature = "alloc")]
+/// A boxed filter trait object
+pub type BoxedFilter<N> = Box<dyn Filter<N>>;
+
+// A blanket impl for `Box<F>` would overlap with the one for closures, as boxed closures are
+// closures themselves. So the trait objects are covered one by one instead.
+macro_rules! impl_filter_for_box {
+    ($( $bound:ident )*) => {
+        #[cfg(feature = "alloc")]
+        /// Boxed filters are filters, so they can be stored in collections and combined
+        impl<'a, N> Filter<N> for Box<dyn Filter<N> $( + $bound )* + 'a> {
+            fn filter(&self, e: &N) -> bool {
+                (**self).filter(e)
+            }
+        }
+    };
+}
+
+impl_filter_for_box!();
+impl_filter_for_box!(Send);
+impl_filter_for_box!(Sync);
+impl_filter_for_box!(Send Sync);
+
+#[cfg(feature = "alloc")]
+/// Filters shared via `Rc` are filters
+impl<N, F: Filter<N> + ?Sized> Filter<N> for Rc<F> {
+    fn filter(&self, e: &N) -> bool {
+        (**self).filter(e)
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Filters shared via `Arc` are filters
+impl<N, F: Filter<N> + ?Sized> Filter<N> for Arc<F> {
+    fn filter(&self, e: &N) -> bool {
+        (**self).filter(e)
+    }
+}
+
+/// Optional filters are filters
+///
+/// **`None` always passes**, so a missing filter does not restrict anything. `Some(f)` delegates
+/// to `f`.
+///
+/// Note that the inherent methods `Option::filter()`, `Option::and()`, `Option::or()` and
+/// `Option::xor()` shadow the `Filter` methods in method call syntax. Use the option as the
+/// argument of a combinator, or call the trait methods explicitly.
+///
+/// ```
+/// use filters::filter::Filter;
+///
+/// let rule: Option<fn(&usize) -> bool> = None;
+/// assert!((|&a: &usize| a > 5).and(rule).filter(&7));
+/// assert!(Filter::filter(&rule, &7));
+///
+/// let rule: Option<fn(&usize) -> bool> = Some(|&a| a % 2 == 0);
+/// assert!(!(|&a: &usize| a > 5).and(rule).filter(&7));
+/// assert!(!Filter::filter(&rule, &7));
+/// ```
+impl<N, F: Filter<N>> Filter<N> for Option<F> {
+    fn filter(&self, e: &N) -> bool {
+        self.as_ref().map(|f| f.filter(e)).unwrap_or(true)
+    }
+}
+
+/// Arrays of filters are filters which pass if all of their elements pass
+///
+/// Evaluation stops at the first element which does not pass. An empty array always passes.
+///
+/// ```
+/// use filters::filter::Filter;
+/// use filters::ops::cmp::GreaterThan;
+///
+/// let f = [GreaterThan(1), GreaterThan(5)];
+/// assert!(!f.filter(&3));
+/// assert!(f.filter(&7));
+/// ```
+impl<N, F: Filter<N>, const K: usize> Filter<N> for [F; K] {
+    fn filter(&self, e: &N) -> bool {
+        self.iter().all(|f| f.filter(e))
+    }
+}
+
+macro_rules! impl_tuple_filter {
+    ($( $generic:ident . $idx:tt ),+) => {
+        /// Tuples of filters are filters which pass if all of their elements pass
+        ///
+        /// Evaluation stops at the first element which does not pass.
+        impl<N, $( $generic: Filter<N>, )+> Filter<N> for ($( $generic, )+) {
+            fn filter(&self, e: &N) -> bool {
+                $( self.$idx.filter(e) )&&+
+            }
+        }
+    };
+}
+
+impl_tuple_filter!(A.0, B.1);
+impl_tuple_filter!(A.0, B.1, C.2);
+impl_tuple_filter!(A.0, B.1, C.2, D.3);
+impl_tuple_filter!(A.0, B.1, C.2, D.3, E.4);
+impl_tuple_filter!(A.0, B.1, C.2, D.3, E.4, F.5);
+impl_tuple_filter!(A.0, B.1, C.2, D.3, E.4, F.5, G.6);
+impl_tuple_filter!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7);
+
+/// Trait for filters which take their input by value
+///
+/// This is useful for filters which need to consume their input. Every `Filter` is a
+/// `FilterOwned` as well, which filters a reference to the value.
+pub trait FilterOwned<N> {
+    /// The function which is used to filter something
+    fn filter_owned(&self, _: N) -> bool;
+}
+
+/// All Filters are FilterOwned
+impl<N, F: Filter<N>> FilterOwned<N> for F {
+    fn filter_owned(&self, e: N) -> bool {
+        self.filter(&e)
+    }
+}
+
 /// The filter trait
 pub trait Filter<N> {
     /// The function which is used to filter something
@@ -126,6 +316,33 @@ pub trait Filter<N> {
         Or::new(self, Or::new(other.into_filter(), other2.into_filter()))
     }
 
+    /// Helper to connect this filter and a list of filters of the same type via logical OR
+    ///
+    /// Unlike chaining `or()`, this keeps the type flat for any number of filters.
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// let a = (|&a: &usize| a == 1) as fn(&usize) -> bool;
+    /// let c = a.or_all(vec![
+    ///     (|&a: &usize| a == 3) as fn(&usize) -> bool,
+    ///     |&a: &usize| a > 10,
+    /// ]);
+    ///
+    /// assert!(c.filter(&1));
+    /// assert!(!c.filter(&2));
+    /// assert!(c.filter(&3));
+    /// assert!(c.filter(&11));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn or_all<I>(self, others: I) -> OrAll<Self>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Self>,
+    {
+        OrAll::new(core::iter::once(self).chain(others).collect())
+    }
+
     /// Helper to connect two filters via logical NOR
     ///
     /// ```
@@ -147,6 +364,107 @@ pub trait Filter<N> {
         Not::new(Or::new(self, other))
     }
 
+    /// Helper to connect three filters via logical NOR
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// let a = (|&a: &usize| { a & 1 == 1 });
+    /// let b = (|&a: &usize| { a & 2 == 2 });
+    /// let c = (|&a: &usize| { a & 4 == 4 });
+    /// let d = a.nor3(b, c); /* !(a || b || c) */
+    ///
+    /// // every combination of a, b and c is encoded in the bits of 0..8
+    /// assert!(d.filter(&0));
+    /// for i in 1..8 {
+    ///     assert!(!d.filter(&i));
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn nor3<F, F2>(self, other: F, other2: F2) -> Not<Or<Self, Or<F::IntoFilt, F2::IntoFilt>>>
+    where
+        Self: Sized,
+        F: IntoFilter<N> + Sized,
+        F2: IntoFilter<N> + Sized,
+    {
+        Not::new(self.or3(other, other2))
+    }
+
+    /// Helper to connect two filters via logical implication
+    ///
+    /// The resulting filter passes whenever `self` does not pass or `other` passes.
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// let a = (|&a: &usize| { a > 10 });
+    /// let b = (|&a: &usize| { a % 2 == 0 });
+    /// let c = a.implies(b); /* a > 10 => a is even */
+    ///
+    /// assert!(c.filter(&3));
+    /// assert!(c.filter(&4));
+    /// assert!(c.filter(&12));
+    /// assert!(!c.filter(&13));
+    /// ```
+    fn implies<F>(self, other: F) -> Or<Not<Self>, F::IntoFilt>
+    where
+        Self: Sized,
+        F: IntoFilter<N> + Sized,
+    {
+        Or::new(Not::new(self), other.into_filter())
+    }
+
+    /// Helper to connect two filters via logical non-implication
+    ///
+    /// The resulting filter passes whenever `self` passes and `other` does not pass. This is the
+    /// same as `and_not()`.
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// for &(a, b, result) in &[
+    ///     (false, false, false),
+    ///     (false, true, false),
+    ///     (true, false, true),
+    ///     (true, true, false),
+    /// ] {
+    ///     let f = (move |_: &()| a).nimply(move |_: &()| b);
+    ///     assert_eq!(f.filter(&()), result);
+    /// }
+    /// ```
+    fn nimply<F>(self, other: F) -> And<Self, Not<F::IntoFilt>>
+    where
+        Self: Sized,
+        F: IntoFilter<N> + Sized,
+    {
+        self.and_not(other)
+    }
+
+    /// Helper to connect two filters via logical converse non-implication
+    ///
+    /// The resulting filter passes whenever `self` does not pass and `other` passes.
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// for &(a, b, result) in &[
+    ///     (false, false, false),
+    ///     (false, true, true),
+    ///     (true, false, false),
+    ///     (true, true, false),
+    /// ] {
+    ///     let f = (move |_: &()| a).converse_nimply(move |_: &()| b);
+    ///     assert_eq!(f.filter(&()), result);
+    /// }
+    /// ```
+    fn converse_nimply<F>(self, other: F) -> And<Not<Self>, F::IntoFilt>
+    where
+        Self: Sized,
+        F: IntoFilter<N> + Sized,
+    {
+        And::new(Not::new(self), other.into_filter())
+    }
+
     /// Helper to connect two filters via logical XOR
     ///
     /// ```
@@ -169,6 +487,81 @@ pub trait Filter<N> {
         XOr::new(self, other)
     }
 
+    /// Helper to connect three filters via logical XOR
+    ///
+    /// This is the parity of the three filters: it passes if an odd number of them passes, so
+    /// also if all three pass. It does not check whether exactly one filter passes.
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// let a = (|&a: &usize| { a & 1 == 1 });
+    /// let b = (|&a: &usize| { a & 2 == 2 });
+    /// let c = (|&a: &usize| { a & 4 == 4 });
+    /// let d = a.xor3(b, c);
+    ///
+    /// // every combination of a, b and c is encoded in the bits of 0..8
+    /// assert!(!d.filter(&0)); // none
+    /// assert!(d.filter(&1));  // a
+    /// assert!(d.filter(&2));  // b
+    /// assert!(!d.filter(&3)); // a, b
+    /// assert!(d.filter(&4));  // c
+    /// assert!(!d.filter(&5)); // a, c
+    /// assert!(!d.filter(&6)); // b, c
+    /// assert!(d.filter(&7));  // a, b, c
+    /// ```
+    fn xor3<F, F2>(self, other: F, other2: F2) -> XOr<Self, XOr<F, F2>>
+    where
+        Self: Sized,
+    {
+        XOr::new(self, XOr::new(other, other2))
+    }
+
+    /// Helper to connect two filters via logical equivalence (XNOR)
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// let a = (|&a: &usize| { a > 3 });
+    /// let b = (|&a: &usize| { a > 5 });
+    /// let c = a.iff(b);
+    ///
+    /// assert!(c.filter(&1));
+    /// assert!(c.filter(&3));
+    /// assert!(!c.filter(&4));
+    /// assert!(!c.filter(&5));
+    /// assert!(c.filter(&9));
+    /// ```
+    fn iff<F>(self, other: F) -> XNor<Self, F::IntoFilt>
+    where
+        Self: Sized,
+        F: IntoFilter<N> + Sized,
+    {
+        XNor::new(self, other.into_filter())
+    }
+
+    /// Helper to use this filter as condition to select which of two filters is applied
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// let large = |&a: &usize| { a > 100 };
+    /// let a = large.select(|&a: &usize| { a % 100 == 0 }, |&a: &usize| { a % 10 == 0 });
+    ///
+    /// assert!(a.filter(&200));
+    /// assert!(!a.filter(&210));
+    /// assert!(a.filter(&20));
+    /// assert!(!a.filter(&21));
+    /// ```
+    fn select<A, B>(self, if_true: A, if_false: B) -> Select<Self, A::IntoFilt, B::IntoFilt>
+    where
+        Self: Sized,
+        A: IntoFilter<N> + Sized,
+        B: IntoFilter<N> + Sized,
+    {
+        Select::new(self, if_true.into_filter(), if_false.into_filter())
+    }
+
     /// Helper to connect two filters via logical AND
     ///
     /// ```
@@ -219,6 +612,33 @@ pub trait Filter<N> {
         And::new(self, And::new(other.into_filter(), other2.into_filter()))
     }
 
+    /// Helper to connect this filter and a list of filters of the same type via logical AND
+    ///
+    /// Unlike chaining `and()`, this keeps the type flat for any number of filters.
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// let a = (|&a: &usize| a > 1) as fn(&usize) -> bool;
+    /// let c = a.and_all(vec![
+    ///     (|&a: &usize| a < 20) as fn(&usize) -> bool,
+    ///     |&a: &usize| a % 2 == 0,
+    /// ]);
+    ///
+    /// assert!(!c.filter(&1));
+    /// assert!(!c.filter(&3));
+    /// assert!(c.filter(&8));
+    /// assert!(!c.filter(&22));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn and_all<I>(self, others: I) -> AndAll<Self>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Self>,
+    {
+        AndAll::new(core::iter::once(self).chain(others).collect())
+    }
+
     /// Helper to connect two filters via logical AND and NOT
     ///
     /// ```
@@ -268,8 +688,36 @@ pub trait Filter<N> {
         Not::new(And::new(self, other))
     }
 
+    /// Helper to connect three filters via logical NAND
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// let a = (|&a: &usize| { a & 1 == 1 });
+    /// let b = (|&a: &usize| { a & 2 == 2 });
+    /// let c = (|&a: &usize| { a & 4 == 4 });
+    /// let d = a.nand3(b, c); /* !(a && b && c) */
+    ///
+    /// // every combination of a, b and c is encoded in the bits of 0..8
+    /// for i in 0..7 {
+    ///     assert!(d.filter(&i));
+    /// }
+    /// assert!(!d.filter(&7));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn nand3<F, F2>(self, other: F, other2: F2) -> Not<And<Self, And<F::IntoFilt, F2::IntoFilt>>>
+    where
+        Self: Sized,
+        F: IntoFilter<N> + Sized,
+        F2: IntoFilter<N> + Sized,
+    {
+        Not::new(self.and3(other, other2))
+    }
+
     /// Helper to transform the input of a filter
     ///
+    /// `map_input_with()` is easier to use and should be preferred.
+    ///
     /// ```
     /// use filters::filter::Filter;
     ///
@@ -293,6 +741,332 @@ pub trait Filter<N> {
         MapInput::new(self, map)
     }
 
+    /// Helper to transform the input of a filter with a closure returning the new input by value
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// let a = (|&a: &usize| { a > 1 });
+    /// let b = (|&a: &i64| { a < 7 }).map_input_with(|&x: &usize| x as i64);
+    /// let c = a.and(b);
+    ///
+    /// assert!(!c.filter(&1));
+    /// assert!(c.filter(&3));
+    /// assert!(!c.filter(&9));
+    /// ```
+    fn map_input_with<T, M>(self, map: M) -> MapInput2<Self, M>
+    where
+        Self: Sized,
+        M: Fn(&T) -> N,
+    {
+        MapInput2::new(self, map)
+    }
+
+    /// Helper to filter a field of the input, given a closure returning a reference to the field
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    /// use filters::ops::cmp::gt;
+    ///
+    /// struct Person {
+    ///     age: u8,
+    /// }
+    ///
+    /// let adult = gt(17).map_input_field(|p: &Person| &p.age);
+    ///
+    /// assert!(!adult.filter(&Person { age: 12 }));
+    /// assert!(adult.filter(&Person { age: 18 }));
+    /// ```
+    fn map_input_field<T, M>(self, field: M) -> MapInputField<Self, M>
+    where
+        Self: Sized,
+        M: Fn(&T) -> &N,
+    {
+        MapInputField::new(self, field)
+    }
+
+    /// Helper to filter references with a filter for the referenced values
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    /// use filters::iter::FilterWith;
+    ///
+    /// let v = vec![1, 7, 3, 9];
+    /// let r: Vec<&usize> = v.iter().filter_with((|&a: &usize| a > 5).on_ref()).collect();
+    ///
+    /// assert_eq!(r, vec![&7, &9]);
+    /// ```
+    fn on_ref(self) -> OnRef<Self>
+    where
+        Self: Sized,
+    {
+        OnRef::new(self)
+    }
+
+    /// Helper to filter values with a filter for references to the values
+    ///
+    /// This is the inverse of `on_ref()`. The filter has to accept references of every lifetime.
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// let a = (|&&a: &&usize| a > 5).on_owned();
+    ///
+    /// assert!(!a.filter(&3));
+    /// assert!(a.filter(&7));
+    /// ```
+    fn on_owned<T>(self) -> OnOwned<Self>
+    where
+        Self: Sized + for<'a> Filter<&'a T>,
+    {
+        OnOwned::new(self)
+    }
+
+    /// Helper to return a default decision if the filter panics
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// let a = (|&a: &usize| { if a == 0 { panic!("zero") } a > 5 });
+    /// let a = a.catch_panic(false);
+    ///
+    /// assert!(!a.filter(&0));
+    /// assert!(!a.filter(&3));
+    /// assert!(a.filter(&7));
+    /// ```
+    #[cfg(feature = "std")]
+    fn catch_panic(self, default: bool) -> CatchPanic<Self>
+    where
+        Self: Sized + RefUnwindSafe,
+    {
+        CatchPanic::new(self, default)
+    }
+
+    /// Helper to precompute the decisions of a filter for a fixed dataset
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// let a = (|&a: &usize| { a > 5 });
+    /// let m = a.materialize(&[3, 7, 9]);
+    ///
+    /// assert!(!m.passes(0));
+    /// assert!(m.passes(1));
+    /// assert!(m.passes(2));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn materialize(&self, items: &[N]) -> MaterializedFilter {
+        MaterializedFilter::new(items.iter().map(|i| self.filter(i)).collect())
+    }
+
+    /// Helper to only accept the `n`th (1-based) item the filter accepts
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// let a = (|&a: &usize| { a % 2 == 0 }).nth_match(2);
+    ///
+    /// assert!(!a.filter(&2));
+    /// assert!(!a.filter(&3));
+    /// assert!(a.filter(&4));
+    /// assert!(!a.filter(&6));
+    /// ```
+    fn nth_match(self, n: usize) -> NthMatch<Self>
+    where
+        Self: Sized,
+    {
+        NthMatch::new(self, n)
+    }
+
+    /// Helper to call a sink with every input the filter accepts
+    ///
+    /// ```
+    /// use std::cell::RefCell;
+    /// use filters::filter::Filter;
+    ///
+    /// let accepted = RefCell::new(Vec::new());
+    /// let a = (|&a: &usize| { a > 5 }).spy(|&a: &usize| accepted.borrow_mut().push(a));
+    ///
+    /// assert!(!a.filter(&3));
+    /// assert!(a.filter(&7));
+    /// assert_eq!(*accepted.borrow(), vec![7]);
+    /// ```
+    fn spy<S>(self, sink: S) -> Spy<Self, S>
+    where
+        Self: Sized,
+        S: Fn(&N),
+    {
+        Spy::new(self, sink)
+    }
+
+    /// Helper to count how often the filter is called and how often it passes
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// let a = (|&a: &usize| { a > 5 }).counting();
+    ///
+    /// assert_eq!((0..10).filter(|x| a.filter(x)).count(), 4);
+    /// assert_eq!(a.calls(), 10);
+    /// assert_eq!(a.matches(), 4);
+    /// ```
+    fn counting(self) -> Counting<Self>
+    where
+        Self: Sized,
+    {
+        Counting::new(self)
+    }
+
+    /// Helper to make the decision of a filter invertible at runtime
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// let a = (|&a: &usize| { a > 5 }).toggleable();
+    /// assert!(a.filter(&7));
+    ///
+    /// a.set_inverted(true);
+    /// assert!(!a.filter(&7));
+    /// ```
+    fn toggleable(self) -> Toggle<Self>
+    where
+        Self: Sized,
+    {
+        Toggle::new(self)
+    }
+
+    /// Helper to call a closure with every input and the decision of the filter for it
+    ///
+    /// ```
+    /// use std::cell::RefCell;
+    /// use filters::filter::Filter;
+    ///
+    /// let seen = RefCell::new(Vec::new());
+    /// let a = (|&a: &usize| { a > 5 }).inspect(|&a, d| seen.borrow_mut().push((a, d)));
+    ///
+    /// assert!(!a.filter(&3));
+    /// assert!(a.filter(&7));
+    /// assert_eq!(*seen.borrow(), vec![(3, false), (7, true)]);
+    /// ```
+    fn inspect<G>(self, g: G) -> Inspect<Self, G>
+    where
+        Self: Sized,
+        G: Fn(&N, bool),
+    {
+        Inspect::new(self, g)
+    }
+
+    /// Helper to wrap every decision of a filter in a `tracing` span
+    ///
+    /// Without the `tracing` feature, the returned filter only delegates to this filter.
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// let a = (|&a: &usize| { a > 5 }).traced("greater_than_five");
+    ///
+    /// assert!(!a.filter(&3));
+    /// assert!(a.filter(&7));
+    /// ```
+    fn traced(self, name: &'static str) -> Traced<Self>
+    where
+        Self: Sized,
+    {
+        Traced::new(self, name)
+    }
+
+    /// Helper to remember the decision of a filter for every input
+    ///
+    /// The filter is only called once per distinct input. See `Cached` for the caveats.
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use filters::filter::Filter;
+    ///
+    /// let calls = Cell::new(0);
+    /// let a = (|&a: &usize| { calls.set(calls.get() + 1); a > 5 }).cached();
+    ///
+    /// assert!(a.filter(&7));
+    /// assert!(a.filter(&7));
+    /// assert_eq!(calls.get(), 1);
+    /// ```
+    #[cfg(feature = "std")]
+    fn cached(self) -> Cached<Self, N>
+    where
+        Self: Sized,
+        N: Eq + Hash + Clone,
+    {
+        Cached::new(self)
+    }
+
+    /// Helper to borrow a filter, so it can be reused after being passed somewhere
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    /// use filters::iter::FilterWith;
+    ///
+    /// struct GreaterThanFive;
+    /// impl Filter<usize> for GreaterThanFive {
+    ///     fn filter(&self, n: &usize) -> bool {
+    ///         *n > 5
+    ///     }
+    /// }
+    ///
+    /// let a = GreaterThanFive;
+    /// let b = vec![3, 7].into_iter().filter_with(a.by_ref()).count();
+    /// let c = vec![6, 7].into_iter().filter_with(a.by_ref()).count();
+    ///
+    /// assert_eq!(b, 1);
+    /// assert_eq!(c, 2);
+    /// assert!(a.filter(&9));
+    /// ```
+    fn by_ref(&self) -> ByRef<'_, Self>
+    where
+        Self: Sized,
+    {
+        ByRef::new(self)
+    }
+
+    /// Helper to negate a borrowed filter, so it can be reused without cloning or moving it
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    ///
+    /// let a = (|&a: &usize| { a > 5 });
+    /// let b = a.as_not();
+    ///
+    /// assert!(b.filter(&3));
+    /// assert!(!b.filter(&7));
+    /// assert!(a.filter(&7));
+    /// ```
+    fn as_not(&self) -> AsNot<'_, Self>
+    where
+        Self: Sized,
+    {
+        AsNot::new(self)
+    }
+
+    /// Helper to box a filter into a trait object
+    ///
+    /// ```
+    /// use filters::filter::{BoxedFilter, Filter};
+    ///
+    /// let filters: Vec<BoxedFilter<usize>> = vec![
+    ///     (|&a: &usize| { a > 5 }).boxed(),
+    ///     (|&a: &usize| { a < 10 }).not().boxed(),
+    /// ];
+    ///
+    /// assert!(filters[0].filter(&7));
+    /// assert!(!filters[1].filter(&7));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn boxed(self) -> BoxedFilter<N>
+    where
+        Self: Sized + 'static,
+    {
+        Box::new(self)
+    }
+
     /// Helper to transform a filter into a FailableFilter
     ///
     /// ```
@@ -313,6 +1087,68 @@ pub trait Filter<N> {
     {
         IntoFailable::new(self)
     }
+
+    /// Helper to box a filter into a failable filter trait object which never fails
+    ///
+    /// This is a shortcut for `into_failable().boxed()`. To store infallible filters next to
+    /// failable ones, map the error type first with `into_failable().map_err(...)`:
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    /// use filters::failable::filter::{BoxedFailableFilter, FailableFilter};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct MyErr;
+    ///
+    /// let filters: Vec<BoxedFailableFilter<u64, MyErr>> = vec![
+    ///     (|&a: &u64| -> Result<bool, MyErr> { if a == 0 { Err(MyErr) } else { Ok(a > 5) } })
+    ///         .boxed(),
+    ///     (|&a: &u64| a < 10)
+    ///         .into_failable()
+    ///         .map_err(|()| MyErr)
+    ///         .boxed(),
+    /// ];
+    ///
+    /// assert_eq!(filters[0].filter(&7), Ok(true));
+    /// assert_eq!(filters[0].filter(&0), Err(MyErr));
+    /// assert_eq!(filters[1].filter(&12), Ok(false));
+    ///
+    /// let b: BoxedFailableFilter<u64, ()> = (|&a: &u64| a < 10).boxed_failable();
+    /// assert_eq!(b.filter(&3), Ok(true));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn boxed_failable(self) -> BoxedFailableFilter<N, ()>
+    where
+        Self: Sized + 'static,
+    {
+        Box::new(IntoFailable::new(self))
+    }
+
+    /// Helper to run a failable filter only for inputs this filter passes
+    ///
+    /// If this filter does not pass, the result is `Ok(false)` and the failable filter is not
+    /// called, so it cannot fail for these inputs.
+    ///
+    /// ```
+    /// use filters::filter::Filter;
+    /// use filters::failable::filter::FailableFilter;
+    ///
+    /// let check = |&a: &usize| -> Result<bool, String> {
+    ///     if a > 100 { Err(format!("{} is too large", a)) } else { Ok(a % 2 == 0) }
+    /// };
+    /// let a = (|&a: &usize| { a <= 100 }).and_then_failable(check);
+    ///
+    /// assert_eq!(a.filter(&4), Ok(true));
+    /// assert_eq!(a.filter(&5), Ok(false));
+    /// assert_eq!(a.filter(&500), Ok(false));
+    /// ```
+    fn and_then_failable<F>(self, f: F) -> AndThenFailable<Self, F>
+    where
+        Self: Sized,
+        F: FailableFilter<N>,
+    {
+        AndThenFailable::new(self, f)
+    }
 }
 
 #[macro_export]
@@ -331,6 +1167,7 @@ mod test {
     use crate::filter::Filter;
     use crate::ops::and::And;
     use crate::ops::bool::Bool;
+    use crate::test_util::EqTo;
 
     #[test]
     fn closures() {
@@ -359,6 +1196,16 @@ mod test {
         assert!(a.filter(&0));
     }
 
+    #[test]
+    fn iff_filter() {
+        let a = (|&a: &usize| a % 2 == 0).iff(|&a: &usize| a % 3 == 0);
+
+        assert!(a.filter(&1));
+        assert!(!a.filter(&2));
+        assert!(!a.filter(&3));
+        assert!(a.filter(&6));
+    }
+
     #[test]
     fn complex_filter() {
         let a = (|&a: &usize| a > 5)
@@ -414,14 +1261,193 @@ mod test {
         assert!(eq.or(Bool::new(true)).filter(&42));
     }
 
-    struct EqTo {
-        pub i: usize,
+    #[test]
+    fn bit_operators() {
+        use crate::ops::cmp::{Equal, GreaterThan, LessThan};
+
+        let a = GreaterThan(5) & LessThan(10);
+        assert!(!a.filter(&5));
+        assert!(a.filter(&7));
+        assert!(!a.filter(&10));
+
+        let a = GreaterThan(5) | Equal(1);
+        assert!(a.filter(&1));
+        assert!(!a.filter(&3));
+        assert!(a.filter(&7));
+
+        let a = GreaterThan(5) ^ GreaterThan(10);
+        assert!(!a.filter(&3));
+        assert!(a.filter(&7));
+        assert!(!a.filter(&12));
+
+        let a = Bool::new(true) & (|&a: &usize| a == 1);
+        assert!(a.filter(&1));
+        assert!(!a.filter(&2));
     }
 
-    impl Filter<usize> for EqTo {
-        fn filter(&self, n: &usize) -> bool {
-            self.i == *n
+    #[test]
+    fn bit_operators_precedence() {
+        use crate::ops::cmp::{Equal, GreaterThan, LessThan};
+
+        // & binds tighter than |
+        let a = GreaterThan(5) & LessThan(10) | Equal(42);
+        let b = (GreaterThan(5) & LessThan(10)) | Equal(42);
+        for i in 0..50 {
+            assert_eq!(a.filter(&i), b.filter(&i));
+        }
+        assert!(a.filter(&42));
+        assert!(a.filter(&7));
+        assert!(!a.filter(&12));
+
+        // & binds tighter than ^, which binds tighter than |
+        let a = Equal(1) ^ GreaterThan(5) & LessThan(10) | Equal(42);
+        let b = (Equal(1) ^ (GreaterThan(5) & LessThan(10))) | Equal(42);
+        for i in 0..50 {
+            assert_eq!(a.filter(&i), b.filter(&i));
         }
+        assert!(a.filter(&1));
+        assert!(a.filter(&7));
+        assert!(!a.filter(&12));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_filters() {
+        use crate::filter::BoxedFilter;
+
+        let filters: Vec<BoxedFilter<usize>> = vec![
+            (|&a: &usize| a > 5).boxed(),
+            Bool::new(false).boxed(),
+            EqTo { i: 3 }.boxed(),
+        ];
+
+        assert_eq!(
+            filters.iter().map(|f| f.filter(&3)).collect::<Vec<_>>(),
+            vec![false, false, true]
+        );
+
+        let combined = filters
+            .into_iter()
+            .fold(Bool::new(false).boxed(), |acc, f| acc.or(f).boxed());
+        assert!(combined.filter(&3));
+        assert!(combined.filter(&7));
+        assert!(!combined.filter(&4));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_failable_mixed() {
+        use crate::failable::filter::{BoxedFailableFilter, FailableFilter};
+
+        #[derive(Debug, PartialEq)]
+        struct MyErr;
+
+        let filters: Vec<BoxedFailableFilter<u64, MyErr>> = vec![
+            (|&a: &u64| -> Result<bool, MyErr> {
+                if a == 0 {
+                    Err(MyErr)
+                } else {
+                    Ok(a > 5)
+                }
+            })
+            .boxed(),
+            (|&a: &u64| a < 10)
+                .into_failable()
+                .map_err(|()| MyErr)
+                .boxed(),
+            (|&a: &u64| a != 8)
+                .boxed_failable()
+                .map_err(|()| MyErr)
+                .boxed(),
+        ];
+
+        let all = |n: u64| -> Result<bool, MyErr> {
+            for f in &filters {
+                if !f.filter(&n)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        };
+
+        assert_eq!(all(7), Ok(true));
+        assert_eq!(all(8), Ok(false));
+        assert_eq!(all(3), Ok(false));
+        assert_eq!(all(0), Err(MyErr));
+    }
+
+    #[test]
+    fn option_filter() {
+        let some = Some(|&a: &usize| a > 5);
+        assert!(Filter::filter(&some, &7));
+        assert!(!Filter::filter(&some, &3));
+
+        let none: Option<fn(&usize) -> bool> = None;
+        assert!(Filter::filter(&none, &7));
+        assert!(Filter::filter(&none, &3));
+
+        let v: Vec<usize> = (0..10)
+            .filter(|a| (|&a: &usize| a < 8).and(none).and(some).filter(a))
+            .collect();
+        assert_eq!(v, vec![6, 7]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_trait_object_filters() {
+        let limit = 5;
+        let borrowing: Box<dyn Filter<usize> + '_> = Box::new(|&a: &usize| a > limit);
+        let sendable: Box<dyn Filter<usize> + Send + Sync> = Box::new(|&a: &usize| a < 10);
+
+        assert!(borrowing.filter(&7));
+        assert!(borrowing.and(sendable).filter(&7));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn rc_filter() {
+        use std::rc::Rc;
+
+        let a: Rc<dyn Filter<usize>> = Rc::new(|&a: &usize| a > 5);
+        let b = Rc::clone(&a).and(|&a: &usize| a < 10);
+
+        assert!(a.filter(&12));
+        assert!(b.filter(&7));
+        assert!(!b.filter(&12));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn arc_filter_across_threads() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let a: Arc<dyn Filter<usize> + Send + Sync> =
+            Arc::new((|&a: &usize| a > 5).and(|&a: &usize| a % 2 == 0));
+
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let a = Arc::clone(&a);
+                thread::spawn(move || (0..20).filter(|x| a.filter(x)).collect::<Vec<usize>>())
+            })
+            .collect();
+
+        let results: Vec<Vec<usize>> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+        assert_eq!(results[0], vec![6, 8, 10, 12, 14, 16, 18]);
+        assert_eq!(results[0], results[1]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn debug_output() {
+        use crate::ops::cmp::GreaterThan;
+
+        let a = GreaterThan(5).and(Bool::new(true)).not();
+        assert_eq!(format!("{:?}", a), "Not(And(GreaterThan(5), Bool(true)))");
+
+        let a = crate::ops::all(vec![Box::new(GreaterThan(5))]);
+        assert_eq!(format!("{:?}", a), "All { filters: 1 }");
     }
 
     #[test]
@@ -433,6 +1459,93 @@ mod test {
         assert!(!eq.filter(&42));
     }
 
+    #[test]
+    fn filter_with_eqto_array() {
+        let same = [EqTo { i: 3 }, EqTo { i: 3 }];
+        assert!(same.filter(&3));
+        assert!(!same.filter(&4));
+
+        let different = [EqTo { i: 3 }, EqTo { i: 4 }];
+        assert!(!different.filter(&3));
+        assert!(!different.filter(&4));
+
+        let empty: [EqTo; 0] = [];
+        assert!(empty.filter(&3));
+    }
+
+    #[test]
+    fn tuple_filters() {
+        use crate::ops::cmp::{gt, lt};
+
+        let pair = (gt(5), EqTo { i: 7 });
+        assert!(pair.filter(&7));
+        assert!(!pair.filter(&8));
+        assert!(!pair.filter(&3));
+
+        let triple = (gt(5), lt(15), |&a: &usize| a % 2 == 0);
+        let v: Vec<usize> = (0..20).filter(|a| triple.filter(a)).collect();
+        assert_eq!(v, vec![6, 8, 10, 12, 14]);
+    }
+
+    #[test]
+    fn and_short_circuits() {
+        let f = (|&a: &usize| a > 5).and(|_: &usize| -> bool { panic!("must not be evaluated") });
+        assert!(!f.filter(&3));
+    }
+
+    #[test]
+    fn or_short_circuits() {
+        let f = (|&a: &usize| a > 5).or(|_: &usize| -> bool { panic!("must not be evaluated") });
+        assert!(f.filter(&7));
+    }
+
+    #[test]
+    fn evaluation_order() {
+        use std::cell::RefCell;
+
+        let order = RefCell::new(Vec::new());
+        let leaf = |name: &'static str, decision: bool| {
+            let order = &order;
+            move |_: &usize| {
+                order.borrow_mut().push(name);
+                decision
+            }
+        };
+
+        let f = leaf("a", true)
+            .and(leaf("b", false))
+            .or(leaf("c", true).and(leaf("d", true)));
+        assert!(f.filter(&0));
+        assert_eq!(*order.borrow(), vec!["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn tuple_filter_short_circuits() {
+        let f = (
+            |&a: &usize| a > 5,
+            |_: &usize| -> bool { panic!("must not be evaluated") },
+        );
+        assert!(!f.filter(&3));
+    }
+
+    #[test]
+    fn filter_with_borrowed_eqto() {
+        use crate::iter::FilterWith;
+
+        let eq = EqTo { i: 3 };
+
+        let a: Vec<usize> = vec![1, 3, 5, 3]
+            .into_iter()
+            .filter_with(eq.by_ref())
+            .collect();
+        let b: Vec<usize> = vec![3, 4].into_iter().filter_with(eq.by_ref()).collect();
+
+        assert_eq!(a, vec![3, 3]);
+        assert_eq!(b, vec![3]);
+        assert!(eq.by_ref().or(EqTo { i: 4 }).filter(&4));
+        assert!(eq.filter(&3));
+    }
+
     #[test]
     fn filter_with_combined_eqto() {
         let aeq = EqTo { i: 1 }.not().and_not(EqTo { i: 17 });
@@ -103,3 +103,68 @@ macro_rules! impl_failable_operators {
         impl_operators!($struct_ident, $self_var $arg_var $filter_impl, );
     };
 }
+
+/// Implements `BitAnd`, `BitOr` and `BitXor` for a Filter struct
+///
+/// `a & b`, `a | b` and `a ^ b` build the same `And`, `Or` and `XOr` filters as the respective
+/// methods of the `Filter` trait.
+macro_rules! impl_bit_operators {
+    ($struct_ident:ident, $( $generic:ident ),*) => {
+        impl<$( $generic, )* Rhs> core::ops::BitAnd<Rhs> for $struct_ident<$( $generic, )*> {
+            type Output = $crate::ops::and::And<Self, Rhs>;
+
+            fn bitand(self, rhs: Rhs) -> Self::Output {
+                $crate::ops::and::And::new(self, rhs)
+            }
+        }
+
+        impl<$( $generic, )* Rhs> core::ops::BitOr<Rhs> for $struct_ident<$( $generic, )*> {
+            type Output = $crate::ops::or::Or<Self, Rhs>;
+
+            fn bitor(self, rhs: Rhs) -> Self::Output {
+                $crate::ops::or::Or::new(self, rhs)
+            }
+        }
+
+        impl<$( $generic, )* Rhs> core::ops::BitXor<Rhs> for $struct_ident<$( $generic, )*> {
+            type Output = $crate::ops::xor::XOr<Self, Rhs>;
+
+            fn bitxor(self, rhs: Rhs) -> Self::Output {
+                $crate::ops::xor::XOr::new(self, rhs)
+            }
+        }
+    };
+}
+
+/// Implements `core::ops::Not` for a Filter struct
+///
+/// `!a` builds the same `Not` filter as `Filter::not()`.
+macro_rules! impl_not_operator {
+    ($struct_ident:ident, $( $generic:ident ),*) => {
+        impl<$( $generic, )*> core::ops::Not for $struct_ident<$( $generic, )*> {
+            type Output = $crate::ops::not::Not<Self>;
+
+            fn not(self) -> Self::Output {
+                $crate::ops::not::Not::new(self)
+            }
+        }
+    };
+}
+
+/// Implements `Debug` for a struct holding boxed filters
+///
+/// Boxed filters cannot be printed, so only their number is shown. The first field passed is
+/// the collection of boxed filters, all further fields are printed as they are.
+#[cfg(feature = "alloc")]
+macro_rules! impl_boxed_debug {
+    ($struct_ident:ident, $( $generic:ident ),*; $boxed:ident: $boxed_idx:tt $(, $field:ident: $idx:tt )*) => {
+        impl<$( $generic, )*> core::fmt::Debug for $struct_ident<$( $generic, )*> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.debug_struct(stringify!($struct_ident))
+                    .field(stringify!($boxed), &self.$boxed_idx.len())
+                    $( .field(stringify!($field), &self.$idx) )*
+                    .finish()
+            }
+        }
+    };
+}
@@ -4,7 +4,21 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
-use crate::filter::Filter;
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::Hash;
+
+use crate::failable::filter::FailableFilter;
+use crate::filter::{Filter, FilterOwned};
+use crate::ops::not::Not;
 
 pub struct FilteredIterator<T, F, I>(F, I)
 where
@@ -27,10 +41,40 @@ where
 
         None
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.1.size_hint().1)
+    }
+}
+
+impl<T, F, I> FusedIterator for FilteredIterator<T, F, I>
+where
+    F: Filter<T>,
+    I: FusedIterator<Item = T>,
+{
+}
+
+impl<T, F, I> DoubleEndedIterator for FilteredIterator<T, F, I>
+where
+    F: Filter<T>,
+    I: DoubleEndedIterator<Item = T>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some(next) = self.1.by_ref().next_back() {
+            if self.0.filter(&next) {
+                return Some(next);
+            }
+        }
+
+        None
+    }
 }
 
 pub trait FilterWith<T, F: Filter<T>>: Iterator<Item = T> + Sized {
     fn filter_with(self, f: F) -> FilteredIterator<T, F, Self>;
+
+    /// Yield only the items which do not pass the filter
+    fn reject(self, f: F) -> FilteredIterator<T, Not<F>, Self>;
 }
 
 impl<I, T, F: Filter<T>> FilterWith<T, F> for I
@@ -40,6 +84,193 @@ where
     fn filter_with(self, f: F) -> FilteredIterator<T, F, Self> {
         FilteredIterator(f, self)
     }
+
+    fn reject(self, f: F) -> FilteredIterator<T, Not<F>, Self> {
+        FilteredIterator(Not::new(f), self)
+    }
+}
+
+pub struct FilteredOwnedIterator<T, F, I>(F, I)
+where
+    T: Clone,
+    F: FilterOwned<T>,
+    I: Iterator<Item = T>;
+
+impl<T, F, I> Iterator for FilteredOwnedIterator<T, F, I>
+where
+    T: Clone,
+    F: FilterOwned<T>,
+    I: Iterator<Item = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(next) = self.1.by_ref().next() {
+            if self.0.filter_owned(next.clone()) {
+                return Some(next);
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.1.size_hint().1)
+    }
+}
+
+pub trait FilterWithOwned<T, F>: Iterator<Item = T> + Sized
+where
+    T: Clone,
+    F: FilterOwned<T>,
+{
+    /// Filter with a filter which takes the items by value
+    ///
+    /// As the filter consumes its input, it is passed a clone of every item, which is cheap for
+    /// `Copy` types.
+    fn filter_with_owned(self, f: F) -> FilteredOwnedIterator<T, F, Self>;
+}
+
+impl<I, T, F> FilterWithOwned<T, F> for I
+where
+    I: Iterator<Item = T>,
+    T: Clone,
+    F: FilterOwned<T>,
+{
+    fn filter_with_owned(self, f: F) -> FilteredOwnedIterator<T, F, Self> {
+        FilteredOwnedIterator(f, self)
+    }
+}
+
+pub struct FilterMapWithIter<T, F, M, I>(F, M, I)
+where
+    F: Filter<T>,
+    I: Iterator<Item = T>;
+
+impl<T, U, F, M, I> Iterator for FilterMapWithIter<T, F, M, I>
+where
+    F: Filter<T>,
+    M: Fn(T) -> U,
+    I: Iterator<Item = T>,
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(next) = self.2.by_ref().next() {
+            if self.0.filter(&next) {
+                return Some((self.1)(next));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.2.size_hint().1)
+    }
+}
+
+pub trait FilterMapWith<T, F: Filter<T>>: Iterator<Item = T> + Sized {
+    /// Yield the mapped items which pass the filter
+    ///
+    /// The mapping is only called for items which pass the filter.
+    fn filter_map_with<U, M>(self, f: F, m: M) -> FilterMapWithIter<T, F, M, Self>
+    where
+        M: Fn(T) -> U;
+}
+
+impl<I, T, F: Filter<T>> FilterMapWith<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn filter_map_with<U, M>(self, f: F, m: M) -> FilterMapWithIter<T, F, M, Self>
+    where
+        M: Fn(T) -> U,
+    {
+        FilterMapWithIter(f, m, self)
+    }
+}
+
+pub struct FilterKeysIter<K, V, F, I>(F, I)
+where
+    F: Filter<K>,
+    I: Iterator<Item = (K, V)>;
+
+impl<K, V, F, I> Iterator for FilterKeysIter<K, V, F, I>
+where
+    F: Filter<K>,
+    I: Iterator<Item = (K, V)>,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(next) = self.1.by_ref().next() {
+            if self.0.filter(&next.0) {
+                return Some(next);
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.1.size_hint().1)
+    }
+}
+
+pub trait FilterKeys<K, V, F: Filter<K>>: Iterator<Item = (K, V)> + Sized {
+    /// Yield only the entries whose key passes the filter
+    fn filter_keys(self, f: F) -> FilterKeysIter<K, V, F, Self>;
+}
+
+impl<I, K, V, F: Filter<K>> FilterKeys<K, V, F> for I
+where
+    I: Iterator<Item = (K, V)>,
+{
+    fn filter_keys(self, f: F) -> FilterKeysIter<K, V, F, Self> {
+        FilterKeysIter(f, self)
+    }
+}
+
+pub struct FilterValuesIter<K, V, F, I>(F, I)
+where
+    F: Filter<V>,
+    I: Iterator<Item = (K, V)>;
+
+impl<K, V, F, I> Iterator for FilterValuesIter<K, V, F, I>
+where
+    F: Filter<V>,
+    I: Iterator<Item = (K, V)>,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(next) = self.1.by_ref().next() {
+            if self.0.filter(&next.1) {
+                return Some(next);
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.1.size_hint().1)
+    }
+}
+
+pub trait FilterValues<K, V, F: Filter<V>>: Iterator<Item = (K, V)> + Sized {
+    /// Yield only the entries whose value passes the filter
+    fn filter_values(self, f: F) -> FilterValuesIter<K, V, F, Self>;
+}
+
+impl<I, K, V, F: Filter<V>> FilterValues<K, V, F> for I
+where
+    I: Iterator<Item = (K, V)>,
+{
+    fn filter_values(self, f: F) -> FilterValuesIter<K, V, F, Self> {
+        FilterValuesIter(f, self)
+    }
 }
 
 pub struct FilterOksIter<T, E, I, F>(I, F)
@@ -68,6 +299,17 @@ where
 
         None
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.0.size_hint().1)
+    }
+}
+
+impl<T, E, I, F> FusedIterator for FilterOksIter<T, E, I, F>
+where
+    F: Filter<T>,
+    I: FusedIterator<Item = Result<T, E>>,
+{
 }
 
 pub trait FilterOks<T, E, I, F>: Iterator<Item = Result<T, E>>
@@ -114,6 +356,17 @@ where
 
         None
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.0.size_hint().1)
+    }
+}
+
+impl<T, E, I, F> FusedIterator for FilterErrIter<T, E, I, F>
+where
+    F: Filter<E>,
+    I: FusedIterator<Item = Result<T, E>>,
+{
 }
 
 pub trait FilterErr<T, E, I, F>: Iterator<Item = Result<T, E>>
@@ -134,89 +387,1732 @@ where
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+pub struct TryFilterOksIter<T, E, I, F>(I, F)
+where
+    F: FailableFilter<T>,
+    I: Iterator<Item = Result<T, E>>;
 
-    #[test]
-    fn test_filter_with() {
-        struct Foo;
-        impl Filter<u64> for Foo {
-            fn filter(&self, u: &u64) -> bool {
-                *u > 5
+impl<T, E, I, F> Iterator for TryFilterOksIter<T, E, I, F>
+where
+    F: FailableFilter<T>,
+    E: From<F::Error>,
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(next) = self.0.by_ref().next() {
+            match next {
+                Err(e) => return Some(Err(e)),
+                Ok(t) => match self.1.filter(&t) {
+                    Ok(true) => return Some(Ok(t)),
+                    Ok(false) => {}
+                    Err(e) => return Some(Err(E::from(e))),
+                },
             }
         }
 
-        let f = Foo;
+        None
+    }
 
-        let v: Vec<u64> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 0]
-            .into_iter()
-            .filter_with(f)
-            .collect();
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.0.size_hint().1)
+    }
+}
 
-        assert_eq!(v, vec![6, 7, 8, 9]);
+impl<T, E, I, F> FusedIterator for TryFilterOksIter<T, E, I, F>
+where
+    F: FailableFilter<T>,
+    E: From<F::Error>,
+    I: FusedIterator<Item = Result<T, E>>,
+{
+}
+
+pub trait TryFilterOks<T, E, F>: Iterator<Item = Result<T, E>> + Sized
+where
+    F: FailableFilter<T>,
+    E: From<F::Error>,
+{
+    /// Yield the `Ok` items which pass the failable filter, and all `Err` items
+    ///
+    /// If the filter fails for an `Ok` item, the error of the filter is yielded instead,
+    /// converted into the error type of the iterator.
+    fn try_filter_oks(self, f: F) -> TryFilterOksIter<T, E, Self, F>;
+}
+
+impl<T, E, I, F> TryFilterOks<T, E, F> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FailableFilter<T>,
+    E: From<F::Error>,
+{
+    fn try_filter_oks(self, f: F) -> TryFilterOksIter<T, E, Self, F> {
+        TryFilterOksIter(self, f)
     }
+}
 
-    #[test]
-    fn test_filter_oks() {
-        struct Foo;
-        impl Filter<u64> for Foo {
-            fn filter(&self, u: &u64) -> bool {
-                *u > 5
+pub struct TryFilterErrsIter<T, E, I, F>(I, F)
+where
+    F: FailableFilter<E>,
+    I: Iterator<Item = Result<T, E>>;
+
+impl<T, E, I, F> Iterator for TryFilterErrsIter<T, E, I, F>
+where
+    F: FailableFilter<E>,
+    E: From<F::Error>,
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(next) = self.0.by_ref().next() {
+            match next {
+                Ok(t) => return Some(Ok(t)),
+                Err(e) => match self.1.filter(&e) {
+                    Ok(true) => return Some(Err(e)),
+                    Ok(false) => {}
+                    Err(e) => return Some(Err(E::from(e))),
+                },
             }
         }
 
-        let f = Foo;
+        None
+    }
 
-        let v: Vec<Result<u64, u64>> = vec![
-            Ok(1),
-            Err(2),
-            Ok(3),
-            Err(4),
-            Ok(5),
-            Err(6),
-            Ok(7),
-            Err(8),
-            Ok(9),
-            Err(0),
-        ]
-        .into_iter()
-        .filter_oks(f)
-        .collect();
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.0.size_hint().1)
+    }
+}
 
-        assert_eq!(
-            v,
-            vec![Err(2), Err(4), Err(6), Ok(7), Err(8), Ok(9), Err(0)]
-        );
+impl<T, E, I, F> FusedIterator for TryFilterErrsIter<T, E, I, F>
+where
+    F: FailableFilter<E>,
+    E: From<F::Error>,
+    I: FusedIterator<Item = Result<T, E>>,
+{
+}
+
+pub trait TryFilterErrs<T, E, F>: Iterator<Item = Result<T, E>> + Sized
+where
+    F: FailableFilter<E>,
+    E: From<F::Error>,
+{
+    /// Yield the `Err` items which pass the failable filter, and all `Ok` items
+    ///
+    /// If the filter fails for an `Err` item, the error of the filter is yielded instead,
+    /// converted into the error type of the iterator.
+    fn try_filter_errs(self, f: F) -> TryFilterErrsIter<T, E, Self, F>;
+}
+
+impl<T, E, I, F> TryFilterErrs<T, E, F> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FailableFilter<E>,
+    E: From<F::Error>,
+{
+    fn try_filter_errs(self, f: F) -> TryFilterErrsIter<T, E, Self, F> {
+        TryFilterErrsIter(self, f)
     }
+}
 
-    #[test]
-    fn test_filter_errs() {
-        struct Foo;
-        impl Filter<u64> for Foo {
-            fn filter(&self, u: &u64) -> bool {
-                *u > 5
+pub struct TryFilteredIterator<T, F, I>(F, I)
+where
+    F: FailableFilter<T>,
+    I: Iterator<Item = T>;
+
+impl<T, F, I> Iterator for TryFilteredIterator<T, F, I>
+where
+    F: FailableFilter<T>,
+    I: Iterator<Item = T>,
+{
+    type Item = Result<T, F::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(next) = self.1.by_ref().next() {
+            match self.0.filter(&next) {
+                Err(e) => return Some(Err(e)),
+                Ok(true) => return Some(Ok(next)),
+                Ok(false) => continue,
             }
         }
 
-        let f = Foo;
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.1.size_hint().1)
+    }
+}
+
+pub trait TryFilterWith<T, F: FailableFilter<T>>: Iterator<Item = T> + Sized {
+    /// Filter with a failable filter
+    ///
+    /// Items for which the filter returns `Ok(true)` are yielded as `Ok`, items for which it
+    /// returns `Ok(false)` are skipped. Errors of the filter are yielded as `Err`, iteration can
+    /// continue afterwards.
+    fn try_filter_with(self, f: F) -> TryFilteredIterator<T, F, Self>;
+}
+
+impl<I, T, F: FailableFilter<T>> TryFilterWith<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn try_filter_with(self, f: F) -> TryFilteredIterator<T, F, Self> {
+        TryFilteredIterator(f, self)
+    }
+}
+
+pub trait TryAnyWith<T, F: FailableFilter<T>>: Iterator<Item = T> + Sized {
+    /// Check whether the failable filter passes for any item
+    ///
+    /// Stops at the first item for which the filter returns `Ok(true)` or an error. The error is
+    /// returned, so later items are not checked.
+    fn try_any_with(self, f: F) -> Result<bool, F::Error>;
+}
+
+impl<I, T, F: FailableFilter<T>> TryAnyWith<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn try_any_with(self, f: F) -> Result<bool, F::Error> {
+        for e in self {
+            if f.filter(&e)? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+pub trait TryAllWith<T, F: FailableFilter<T>>: Iterator<Item = T> + Sized {
+    /// Check whether the failable filter passes for all items
+    ///
+    /// Stops at the first item for which the filter returns `Ok(false)` or an error. The error
+    /// is returned, so later items are not checked.
+    fn try_all_with(self, f: F) -> Result<bool, F::Error>;
+}
+
+impl<I, T, F: FailableFilter<T>> TryAllWith<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn try_all_with(self, f: F) -> Result<bool, F::Error> {
+        for e in self {
+            if !f.filter(&e)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+pub trait CountTransitions<T, F: Filter<T>>: Iterator<Item = T> + Sized {
+    /// Count how often the decision of the filter flips between adjacent items
+    fn count_transitions(self, f: F) -> usize;
+}
+
+impl<I, T, F: Filter<T>> CountTransitions<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn count_transitions(self, f: F) -> usize {
+        let mut decisions = self.map(|e| f.filter(&e));
+        let mut previous = match decisions.next() {
+            Some(d) => d,
+            None => return 0,
+        };
+
+        decisions
+            .filter(|&d| {
+                let flipped = d != previous;
+                previous = d;
+                flipped
+            })
+            .count()
+    }
+}
+
+#[cfg(feature = "std")]
+pub trait CollectMapWith<K, V, F>: Iterator<Item = (K, V)> + Sized
+where
+    K: Eq + Hash,
+    F: Filter<V>,
+{
+    /// Collect all pairs whose value passes the filter into a map
+    ///
+    /// If a key occurs more than once, the last pair with a passing value wins.
+    fn collect_map_with(self, f: F) -> HashMap<K, V>;
+}
+
+#[cfg(feature = "std")]
+impl<I, K, V, F> CollectMapWith<K, V, F> for I
+where
+    I: Iterator<Item = (K, V)>,
+    K: Eq + Hash,
+    F: Filter<V>,
+{
+    fn collect_map_with(self, f: F) -> HashMap<K, V> {
+        self.filter(|(_, v)| f.filter(v)).collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub struct FilterWithNeighborsIter<T, F, I>
+where
+    F: Filter<T>,
+    I: Iterator<Item = T>,
+{
+    iter: I,
+    filter: F,
+    radius: usize,
+    ahead: VecDeque<(T, bool)>,
+    behind: VecDeque<bool>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T, F, I> Iterator for FilterWithNeighborsIter<T, F, I>
+where
+    F: Filter<T>,
+    I: Iterator<Item = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while self.ahead.len() <= self.radius {
+                match self.iter.next() {
+                    Some(next) => {
+                        let decision = self.filter.filter(&next);
+                        self.ahead.push_back((next, decision));
+                    }
+                    None => break,
+                }
+            }
+
+            let (item, decision) = self.ahead.pop_front()?;
+            let accept =
+                decision && self.behind.iter().all(|&d| d) && self.ahead.iter().all(|&(_, d)| d);
+
+            self.behind.push_back(decision);
+            if self.behind.len() > self.radius {
+                self.behind.pop_front();
+            }
+
+            if accept {
+                return Some(item);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub trait FilterWithNeighbors<T, F: Filter<T>>: Iterator<Item = T> + Sized {
+    /// Yield only the items for which the filter accepts the item itself and all items within
+    /// `radius` positions before and after it
+    ///
+    /// Up to `radius` items are buffered ahead of the yielded one. At the start and the end of
+    /// the iterator, only the neighbors which exist are considered.
+    fn filter_with_neighbors(self, radius: usize, f: F) -> FilterWithNeighborsIter<T, F, Self>;
+}
+
+#[cfg(feature = "alloc")]
+impl<I, T, F: Filter<T>> FilterWithNeighbors<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn filter_with_neighbors(self, radius: usize, f: F) -> FilterWithNeighborsIter<T, F, Self> {
+        FilterWithNeighborsIter {
+            iter: self,
+            filter: f,
+            radius,
+            ahead: VecDeque::new(),
+            behind: VecDeque::new(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub struct GroupAdjacentWithIter<T, F, I>
+where
+    F: Filter<T>,
+    I: Iterator<Item = T>,
+{
+    iter: I,
+    filter: F,
+    pending: Option<(T, bool)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T, F, I> Iterator for GroupAdjacentWithIter<T, F, I>
+where
+    F: Filter<T>,
+    I: Iterator<Item = T>,
+{
+    type Item = (bool, Vec<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, decision) = match self.pending.take() {
+            Some(pending) => pending,
+            None => {
+                let first = self.iter.next()?;
+                let decision = self.filter.filter(&first);
+                (first, decision)
+            }
+        };
+
+        let mut group = vec![first];
+        for next in self.iter.by_ref() {
+            let next_decision = self.filter.filter(&next);
+            if next_decision != decision {
+                self.pending = Some((next, next_decision));
+                break;
+            }
+            group.push(next);
+        }
+
+        Some((decision, group))
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub trait GroupAdjacentWith<T, F: Filter<T>>: Iterator<Item = T> + Sized {
+    /// Group adjacent items for which the filter decides the same
+    ///
+    /// Every group is yielded together with the decision of the filter for its items.
+    fn group_adjacent_with(self, f: F) -> GroupAdjacentWithIter<T, F, Self>;
+}
+
+#[cfg(feature = "alloc")]
+impl<I, T, F: Filter<T>> GroupAdjacentWith<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn group_adjacent_with(self, f: F) -> GroupAdjacentWithIter<T, F, Self> {
+        GroupAdjacentWithIter {
+            iter: self,
+            filter: f,
+            pending: None,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub struct WindowsWithIter<T, F, I>
+where
+    F: for<'a> Filter<&'a [T]>,
+    I: Iterator<Item = T>,
+{
+    iter: I,
+    filter: F,
+    size: usize,
+    window: VecDeque<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T, F, I> Iterator for WindowsWithIter<T, F, I>
+where
+    F: for<'a> Filter<&'a [T]>,
+    I: Iterator<Item = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while self.window.len() < self.size {
+                self.window.push_back(self.iter.next()?);
+            }
+
+            let window: &[T] = self.window.make_contiguous();
+            let accept = self.filter.filter(&window);
+            let first = self.window.pop_front();
+
+            if accept {
+                return first;
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let upper = self
+            .iter
+            .size_hint()
+            .1
+            .and_then(|u| u.checked_add(self.window.len()))
+            .map(|u| u.saturating_sub(self.size - 1));
+        (0, upper)
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub trait WindowsWith<T, F>: Iterator<Item = T> + Sized
+where
+    F: for<'a> Filter<&'a [T]>,
+{
+    /// Yield the first item of every window of `size` items for which the filter passes
+    ///
+    /// The windows overlap, like the ones of `slice::windows()`. The last `size - 1` items are
+    /// never yielded, as there is no complete window starting with them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    fn windows_with(self, size: usize, f: F) -> WindowsWithIter<T, F, Self>;
+}
+
+#[cfg(feature = "alloc")]
+impl<I, T, F> WindowsWith<T, F> for I
+where
+    I: Iterator<Item = T>,
+    F: for<'a> Filter<&'a [T]>,
+{
+    fn windows_with(self, size: usize, f: F) -> WindowsWithIter<T, F, Self> {
+        assert!(size != 0, "window size must be non-zero");
+
+        WindowsWithIter {
+            iter: self,
+            filter: f,
+            size,
+            window: VecDeque::new(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub struct ChunksWithIter<T, F, I>
+where
+    F: Filter<Vec<T>>,
+    I: Iterator<Item = T>,
+{
+    iter: I,
+    filter: F,
+    size: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<T, F, I> Iterator for ChunksWithIter<T, F, I>
+where
+    F: Filter<Vec<T>>,
+    I: Iterator<Item = T>,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let chunk: Vec<T> = self.iter.by_ref().take(self.size).collect();
+            if chunk.is_empty() {
+                return None;
+            }
+
+            if self.filter.filter(&chunk) {
+                return Some(chunk);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub trait ChunksWith<T, F: Filter<Vec<T>>>: Iterator<Item = T> + Sized {
+    /// Yield the chunks of `size` items for which the filter passes
+    ///
+    /// The chunks do not overlap. The last chunk is shorter than `size` if the number of items
+    /// is not divisible by `size`, and is passed to the filter as well.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    fn chunks_with(self, size: usize, f: F) -> ChunksWithIter<T, F, Self>;
+}
+
+#[cfg(feature = "alloc")]
+impl<I, T, F: Filter<Vec<T>>> ChunksWith<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn chunks_with(self, size: usize, f: F) -> ChunksWithIter<T, F, Self> {
+        assert!(size != 0, "chunk size must be non-zero");
+
+        ChunksWithIter {
+            iter: self,
+            filter: f,
+            size,
+        }
+    }
+}
+
+pub struct DedupByVerdictIter<T, F, I>
+where
+    F: Filter<T>,
+    I: Iterator<Item = T>,
+{
+    iter: I,
+    filter: F,
+    last: Option<bool>,
+}
+
+impl<T, F, I> Iterator for DedupByVerdictIter<T, F, I>
+where
+    F: Filter<T>,
+    I: Iterator<Item = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for next in self.iter.by_ref() {
+            let decision = self.filter.filter(&next);
+            if self.last != Some(decision) {
+                self.last = Some(decision);
+                return Some(next);
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+pub trait DedupByVerdict<T, F: Filter<T>>: Iterator<Item = T> + Sized {
+    /// Yield only the items for which the filter decides differently than for the previous item
+    ///
+    /// The first item is always yielded.
+    fn dedup_by_verdict(self, f: F) -> DedupByVerdictIter<T, F, Self>;
+}
+
+impl<I, T, F: Filter<T>> DedupByVerdict<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn dedup_by_verdict(self, f: F) -> DedupByVerdictIter<T, F, Self> {
+        DedupByVerdictIter {
+            iter: self,
+            filter: f,
+            last: None,
+        }
+    }
+}
+
+pub trait FoldDecisions<T, F: Filter<T>>: Iterator<Item = T> + Sized {
+    /// Fold every item together with the decision of the filter for it
+    fn fold_decisions<Acc, Fun>(self, f: F, init: Acc, fun: Fun) -> Acc
+    where
+        Fun: FnMut(Acc, &T, bool) -> Acc;
+}
+
+impl<I, T, F: Filter<T>> FoldDecisions<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn fold_decisions<Acc, Fun>(self, f: F, init: Acc, mut fun: Fun) -> Acc
+    where
+        Fun: FnMut(Acc, &T, bool) -> Acc,
+    {
+        self.fold(init, |acc, e| {
+            let decision = f.filter(&e);
+            fun(acc, &e, decision)
+        })
+    }
+}
+
+pub trait PartitionWith<T, F: Filter<T>>: Iterator<Item = T> + Sized {
+    /// Split the items into the ones which pass the filter and the ones which do not
+    ///
+    /// Like `Iterator::partition`, but with a filter instead of a closure.
+    fn partition_with<B>(self, f: F) -> (B, B)
+    where
+        B: Default + Extend<T>;
+}
+
+impl<I, T, F: Filter<T>> PartitionWith<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn partition_with<B>(self, f: F) -> (B, B)
+    where
+        B: Default + Extend<T>,
+    {
+        self.partition(|e| f.filter(e))
+    }
+}
+
+pub trait CountWith<T, F: Filter<T>>: Iterator<Item = T> + Sized {
+    /// Count the items which pass the filter
+    fn count_with(self, f: F) -> usize;
+}
+
+impl<I, T, F: Filter<T>> CountWith<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn count_with(self, f: F) -> usize {
+        self.filter(|e| f.filter(e)).count()
+    }
+}
+
+pub trait AnyWith<T, F: Filter<T>>: Iterator<Item = T> + Sized {
+    /// Check whether any item passes the filter
+    ///
+    /// Stops at the first item which passes.
+    fn any_with(self, f: F) -> bool;
+}
+
+impl<I, T, F: Filter<T>> AnyWith<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn any_with(mut self, f: F) -> bool {
+        self.any(|e| f.filter(&e))
+    }
+}
+
+pub trait AllWith<T, F: Filter<T>>: Iterator<Item = T> + Sized {
+    /// Check whether all items pass the filter
+    ///
+    /// Stops at the first item which does not pass.
+    fn all_with(self, f: F) -> bool;
+}
+
+impl<I, T, F: Filter<T>> AllWith<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn all_with(mut self, f: F) -> bool {
+        self.all(|e| f.filter(&e))
+    }
+}
+
+pub trait FindWith<T, F: Filter<T>>: Iterator<Item = T> + Sized {
+    /// Return the first item which passes the filter
+    ///
+    /// Like `Iterator::find`, the iterator can be used further after the match.
+    fn find_with(&mut self, f: F) -> Option<T>;
+}
+
+impl<I, T, F: Filter<T>> FindWith<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn find_with(&mut self, f: F) -> Option<T> {
+        self.find(|e| f.filter(e))
+    }
+}
+
+pub trait PositionWith<T, F: Filter<T>>: Iterator<Item = T> + Sized {
+    /// Return the index of the first item which passes the filter
+    ///
+    /// Like `Iterator::position`, the iterator can be used further after the match.
+    fn position_with(&mut self, f: F) -> Option<usize>;
+}
+
+impl<I, T, F: Filter<T>> PositionWith<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn position_with(&mut self, f: F) -> Option<usize> {
+        self.position(|e| f.filter(&e))
+    }
+}
+
+pub struct TakeWhileWithIter<T, F, I>(F, I, bool)
+where
+    F: Filter<T>,
+    I: Iterator<Item = T>;
+
+impl<T, F, I> Iterator for TakeWhileWithIter<T, F, I>
+where
+    F: Filter<T>,
+    I: Iterator<Item = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.2 {
+            return None;
+        }
+
+        match self.1.next() {
+            Some(next) if self.0.filter(&next) => Some(next),
+            _ => {
+                self.2 = true;
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.2 {
+            (0, Some(0))
+        } else {
+            (0, self.1.size_hint().1)
+        }
+    }
+}
+
+impl<T, F, I> FusedIterator for TakeWhileWithIter<T, F, I>
+where
+    F: Filter<T>,
+    I: FusedIterator<Item = T>,
+{
+}
+
+pub trait TakeWhileWith<T, F: Filter<T>>: Iterator<Item = T> + Sized {
+    /// Yield items until the first one which does not pass the filter
+    ///
+    /// The first rejected item is consumed, but not yielded. Once an item was rejected or the
+    /// iterator ended, `None` is returned without advancing the iterator any further.
+    fn take_while_with(self, f: F) -> TakeWhileWithIter<T, F, Self>;
+}
+
+impl<I, T, F: Filter<T>> TakeWhileWith<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn take_while_with(self, f: F) -> TakeWhileWithIter<T, F, Self> {
+        TakeWhileWithIter(f, self, false)
+    }
+}
+
+pub struct SkipWhileWithIter<T, F, I>(F, I, bool)
+where
+    F: Filter<T>,
+    I: Iterator<Item = T>;
+
+impl<T, F, I> Iterator for SkipWhileWithIter<T, F, I>
+where
+    F: Filter<T>,
+    I: Iterator<Item = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.2 {
+            return self.1.next();
+        }
+
+        while let Some(next) = self.1.by_ref().next() {
+            if !self.0.filter(&next) {
+                self.2 = true;
+                return Some(next);
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.2 {
+            self.1.size_hint()
+        } else {
+            (0, self.1.size_hint().1)
+        }
+    }
+}
+
+impl<T, F, I> FusedIterator for SkipWhileWithIter<T, F, I>
+where
+    F: Filter<T>,
+    I: FusedIterator<Item = T>,
+{
+}
+
+pub trait SkipWhileWith<T, F: Filter<T>>: Iterator<Item = T> + Sized {
+    /// Skip items while they pass the filter, then yield all remaining items
+    fn skip_while_with(self, f: F) -> SkipWhileWithIter<T, F, Self>;
+}
+
+impl<I, T, F: Filter<T>> SkipWhileWith<T, F> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn skip_while_with(self, f: F) -> SkipWhileWithIter<T, F, Self> {
+        SkipWhileWithIter(f, self, false)
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub trait VecRetainExt<T> {
+    /// Retain only the elements for which the filter passes, in place
+    fn retain_with<F: Filter<T>>(&mut self, f: &F);
+}
+
+#[cfg(feature = "alloc")]
+impl<T> VecRetainExt<T> for Vec<T> {
+    fn retain_with<F: Filter<T>>(&mut self, f: &F) {
+        self.retain(|e| f.filter(e))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_filter_with() {
+        struct Foo;
+        impl Filter<u64> for Foo {
+            fn filter(&self, u: &u64) -> bool {
+                *u > 5
+            }
+        }
+
+        let f = Foo;
+
+        let v: Vec<u64> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 0]
+            .into_iter()
+            .filter_with(f)
+            .collect();
+
+        assert_eq!(v, vec![6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_filter_with_rev() {
+        let f = |u: &u64| *u > 5;
+        let v: Vec<u64> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 0];
+
+        let mut forward: Vec<u64> = v.clone().into_iter().filter_with(f).collect();
+        let backward: Vec<u64> = v.into_iter().filter_with(f).rev().collect();
+
+        forward.reverse();
+        assert_eq!(backward, forward);
+        assert_eq!((0..100).filter_with(f).next_back(), Some(99));
+    }
+
+    #[test]
+    fn test_filter_with_both_ends() {
+        let mut iter = (0..10).filter_with(|u: &u64| *u % 2 == 1);
+
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(9));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next_back(), Some(7));
+        assert_eq!(iter.next(), Some(5));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_size_hint() {
+        let f = |u: &u64| *u > 5;
+
+        assert_eq!((0..10).filter_with(f).size_hint(), (0, Some(10)));
+
+        let v: Vec<Result<u64, u64>> = vec![Ok(1), Err(2), Ok(7)];
+        assert_eq!(
+            v.clone().into_iter().filter_oks(f).size_hint(),
+            (0, Some(3))
+        );
+        assert_eq!(v.into_iter().filter_errs(f).size_hint(), (0, Some(3)));
+
+        let mut iter = (0..10).filter_with(f);
+        iter.next();
+        assert_eq!(iter.size_hint(), (0, Some(3)));
+    }
+
+    #[test]
+    fn test_fused() {
+        fn assert_fused<I: FusedIterator>(_: &I) {}
+
+        let mut iter = (0..10).filter_with(|u: &u64| *u > 7);
+        assert_fused(&iter);
+
+        assert_eq!(iter.by_ref().count(), 2);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_reject() {
+        let f = |u: &u64| *u > 5;
+        let v: Vec<u64> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 0];
+
+        let kept: Vec<u64> = v.clone().into_iter().filter_with(f).collect();
+        let rejected: Vec<u64> = v.clone().into_iter().reject(f).collect();
+
+        assert_eq!(rejected, vec![1, 2, 3, 4, 5, 0]);
+        assert_eq!(kept.len() + rejected.len(), v.len());
+        assert!(rejected.iter().all(|u| !kept.contains(u)));
+    }
+
+    #[test]
+    fn test_filter_with_owned_copy() {
+        struct Odd;
+        impl FilterOwned<u64> for Odd {
+            fn filter_owned(&self, u: u64) -> bool {
+                u % 2 == 1
+            }
+        }
+
+        let v: Vec<u64> = (0..10).filter_with_owned(Odd).collect();
+        assert_eq!(v, vec![1, 3, 5, 7, 9]);
+
+        let v: Vec<u64> = (0..10).filter_with_owned(|u: &u64| *u > 6).collect();
+        assert_eq!(v, vec![7, 8, 9]);
+    }
+
+    #[test]
+    fn test_filter_with_owned_string() {
+        struct Palindrome;
+        impl FilterOwned<String> for Palindrome {
+            fn filter_owned(&self, s: String) -> bool {
+                let mut reversed = s.clone().into_bytes();
+                reversed.reverse();
+                s.into_bytes() == reversed
+            }
+        }
+
+        let v: Vec<String> = vec!["abba", "abc", "x", "xyzyx"]
+            .into_iter()
+            .map(String::from)
+            .filter_with_owned(Palindrome)
+            .collect();
+
+        assert_eq!(v, vec!["abba", "x", "xyzyx"]);
+        assert!(Palindrome.filter_owned(String::from("otto")));
+    }
+
+    #[test]
+    fn test_filter_map_with() {
+        let v: Vec<u64> = (0..10)
+            .filter_map_with(|u: &u64| *u > 5, |u| u * 2)
+            .collect();
+
+        assert_eq!(v, vec![12, 14, 16, 18]);
+    }
+
+    #[test]
+    fn test_filter_map_with_maps_only_passing_items() {
+        let v: Vec<String> = vec![1, 7, 3, 9]
+            .into_iter()
+            .filter_map_with(
+                |u: &u64| *u > 5,
+                |u| {
+                    assert!(u > 5);
+                    u.to_string()
+                },
+            )
+            .collect();
+
+        assert_eq!(v, vec!["7", "9"]);
+    }
+
+    #[test]
+    fn test_filter_oks() {
+        struct Foo;
+        impl Filter<u64> for Foo {
+            fn filter(&self, u: &u64) -> bool {
+                *u > 5
+            }
+        }
+
+        let f = Foo;
+
+        let v: Vec<Result<u64, u64>> = vec![
+            Ok(1),
+            Err(2),
+            Ok(3),
+            Err(4),
+            Ok(5),
+            Err(6),
+            Ok(7),
+            Err(8),
+            Ok(9),
+            Err(0),
+        ]
+        .into_iter()
+        .filter_oks(f)
+        .collect();
+
+        assert_eq!(
+            v,
+            vec![Err(2), Err(4), Err(6), Ok(7), Err(8), Ok(9), Err(0)]
+        );
+    }
+
+    #[test]
+    fn test_filter_errs() {
+        struct Foo;
+        impl Filter<u64> for Foo {
+            fn filter(&self, u: &u64) -> bool {
+                *u > 5
+            }
+        }
+
+        let f = Foo;
+
+        let v: Vec<Result<u64, u64>> = vec![
+            Ok(1),
+            Err(2),
+            Ok(3),
+            Err(4),
+            Ok(5),
+            Err(6),
+            Ok(7),
+            Err(8),
+            Ok(9),
+            Err(0),
+        ]
+        .into_iter()
+        .filter_errs(f)
+        .collect();
+
+        assert_eq!(v, vec![Ok(1), Ok(3), Ok(5), Err(6), Ok(7), Err(8), Ok(9)]);
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum TryFilterError {
+        Item(u64),
+        Filter(&'static str),
+    }
+
+    impl From<&'static str> for TryFilterError {
+        fn from(e: &'static str) -> TryFilterError {
+            TryFilterError::Filter(e)
+        }
+    }
+
+    fn failable_gt_five(u: &u64) -> Result<bool, &'static str> {
+        if *u == 0 {
+            Err("zero")
+        } else {
+            Ok(*u > 5)
+        }
+    }
+
+    #[test]
+    fn test_try_filter_oks() {
+        use self::TryFilterError::{Filter, Item};
+
+        let v: Vec<Result<u64, TryFilterError>> =
+            vec![Ok(1), Err(Item(2)), Ok(7), Ok(0), Err(Item(0)), Ok(9)]
+                .into_iter()
+                .try_filter_oks(failable_gt_five)
+                .collect();
+
+        assert_eq!(
+            v,
+            vec![
+                Err(Item(2)),
+                Ok(7),
+                Err(Filter("zero")),
+                Err(Item(0)),
+                Ok(9)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_try_filter_errs() {
+        use self::TryFilterError::Filter;
+
+        let v: Vec<Result<u64, TryFilterError>> = vec![Ok(1), Err(2), Err(7), Err(0), Ok(0)]
+            .into_iter()
+            .map(|r| r.map_err(TryFilterError::Item))
+            .try_filter_errs(|e: &TryFilterError| match e {
+                TryFilterError::Item(u) => failable_gt_five(u),
+                TryFilterError::Filter(_) => Ok(true),
+            })
+            .collect();
+
+        assert_eq!(
+            v,
+            vec![
+                Ok(1),
+                Err(TryFilterError::Item(7)),
+                Err(Filter("zero")),
+                Ok(0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_try_filter_with() {
+        let f = |u: &u64| -> Result<bool, &'static str> {
+            if *u == 0 {
+                Err("zero")
+            } else {
+                Ok(*u > 5)
+            }
+        };
+
+        let v: Vec<Result<u64, &'static str>> = vec![1, 7, 0, 3, 9, 0]
+            .into_iter()
+            .try_filter_with(f)
+            .collect();
+
+        assert_eq!(v, vec![Ok(7), Err("zero"), Ok(9), Err("zero")]);
+    }
+
+    #[test]
+    fn test_try_filter_with_collect_result() {
+        let f = |u: &u64| -> Result<bool, &'static str> {
+            if *u == 0 {
+                Err("zero")
+            } else {
+                Ok(*u > 5)
+            }
+        };
+
+        let ok: Result<Vec<u64>, _> = vec![1, 7, 9].into_iter().try_filter_with(f).collect();
+        assert_eq!(ok, Ok(vec![7, 9]));
+
+        let err: Result<Vec<u64>, _> = vec![1, 0, 9].into_iter().try_filter_with(f).collect();
+        assert_eq!(err, Err("zero"));
+    }
+
+    #[test]
+    fn test_count_transitions() {
+        let f = |u: &u64| *u > 5;
+
+        assert_eq!(vec![1, 7, 2, 8, 3].into_iter().count_transitions(f), 4);
+        assert_eq!(vec![1, 2, 7, 8, 9, 3].into_iter().count_transitions(f), 2);
+    }
+
+    #[test]
+    fn test_count_transitions_constant() {
+        let f = |u: &u64| *u > 5;
+
+        assert_eq!(vec![1, 2, 3, 4].into_iter().count_transitions(f), 0);
+        assert_eq!(vec![6, 7, 8].into_iter().count_transitions(f), 0);
+        assert_eq!(vec![9].into_iter().count_transitions(f), 0);
+        assert_eq!(Vec::new().into_iter().count_transitions(f), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_collect_map_with() {
+        let m = vec![("a", 1), ("b", 7), ("c", 3), ("d", 9)]
+            .into_iter()
+            .collect_map_with(|v: &u64| *v > 5);
 
-        let v: Vec<Result<u64, u64>> = vec![
-            Ok(1),
-            Err(2),
-            Ok(3),
-            Err(4),
-            Ok(5),
-            Err(6),
-            Ok(7),
-            Err(8),
-            Ok(9),
-            Err(0),
+        assert_eq!(m.len(), 2);
+        assert_eq!(m.get("b"), Some(&7));
+        assert_eq!(m.get("d"), Some(&9));
+        assert_eq!(m.get("a"), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_collect_map_with_duplicate_keys() {
+        let m = vec![("a", 6), ("a", 8), ("a", 1), ("b", 2)]
+            .into_iter()
+            .collect_map_with(|v: &u64| *v > 5);
+
+        assert_eq!(m.len(), 1);
+        assert_eq!(m.get("a"), Some(&8));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_filter_with_neighbors() {
+        let v: Vec<u64> = vec![9, 8, 1, 7, 8, 9, 7, 6, 2, 9]
+            .into_iter()
+            .filter_with_neighbors(1, |u: &u64| *u > 5)
+            .collect();
+
+        assert_eq!(v, vec![9, 8, 9, 7]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_filter_with_neighbors_radius_zero() {
+        let v: Vec<u64> = vec![9, 8, 1, 7, 2]
+            .into_iter()
+            .filter_with_neighbors(0, |u: &u64| *u > 5)
+            .collect();
+
+        assert_eq!(v, vec![9, 8, 7]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_filter_with_neighbors_large_radius() {
+        let all_pass: Vec<u64> = vec![6, 7, 8]
+            .into_iter()
+            .filter_with_neighbors(10, |u: &u64| *u > 5)
+            .collect();
+        assert_eq!(all_pass, vec![6, 7, 8]);
+
+        let one_fails: Vec<u64> = vec![6, 7, 8, 1]
+            .into_iter()
+            .filter_with_neighbors(10, |u: &u64| *u > 5)
+            .collect();
+        assert!(one_fails.is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_filter_with_neighbors_sides_differ() {
+        let left_fails: Vec<u64> = vec![1, 9, 9]
+            .into_iter()
+            .filter_with_neighbors(1, |u: &u64| *u > 5)
+            .collect();
+        assert_eq!(left_fails, vec![9]);
+
+        let right_fails: Vec<u64> = vec![9, 9, 1]
+            .into_iter()
+            .filter_with_neighbors(1, |u: &u64| *u > 5)
+            .collect();
+        assert_eq!(right_fails, vec![9]);
+
+        let both_neighbors_pass: Vec<u64> = vec![1, 9, 8, 9, 1]
+            .into_iter()
+            .filter_with_neighbors(1, |u: &u64| *u > 5)
+            .collect();
+        assert_eq!(both_neighbors_pass, vec![8]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_filter_with_neighbors_max_radius() {
+        let v: Vec<u64> = vec![6, 7, 8]
+            .into_iter()
+            .filter_with_neighbors(usize::MAX, |u: &u64| *u > 5)
+            .collect();
+        assert_eq!(v, vec![6, 7, 8]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_group_adjacent_with() {
+        use crate::ops::cmp::gt;
+
+        let v: Vec<(bool, Vec<u64>)> = vec![1, 2, 8, 9, 3]
+            .into_iter()
+            .group_adjacent_with(gt(5))
+            .collect();
+
+        assert_eq!(
+            v,
+            vec![(false, vec![1, 2]), (true, vec![8, 9]), (false, vec![3])]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_group_adjacent_with_edge_cases() {
+        use crate::ops::cmp::gt;
+
+        let v: Vec<(bool, Vec<u64>)> = Vec::new().into_iter().group_adjacent_with(gt(5)).collect();
+        assert!(v.is_empty());
+
+        let v: Vec<(bool, Vec<u64>)> = vec![7].into_iter().group_adjacent_with(gt(5)).collect();
+        assert_eq!(v, vec![(true, vec![7])]);
+
+        let v: Vec<(bool, Vec<u64>)> = vec![1, 2, 3]
+            .into_iter()
+            .group_adjacent_with(gt(5))
+            .collect();
+        assert_eq!(v, vec![(false, vec![1, 2, 3])]);
+    }
+
+    #[test]
+    fn test_fold_decisions() {
+        let score = vec![1, 7, 3, 9, 2].into_iter().fold_decisions(
+            |u: &i64| *u > 5,
+            0,
+            |acc, u, passed| {
+                if passed {
+                    acc + u
+                } else {
+                    acc - u
+                }
+            },
+        );
+
+        assert_eq!(score, 7 + 9 - 1 - 3 - 2);
+    }
+
+    #[test]
+    fn test_partition_with() {
+        let (matched, unmatched): (Vec<u64>, Vec<u64>) = vec![1, 7, 3, 9, 2, 6]
+            .into_iter()
+            .partition_with(|u: &u64| *u > 5);
+
+        assert_eq!(matched, vec![7, 9, 6]);
+        assert_eq!(unmatched, vec![1, 3, 2]);
+    }
+
+    #[test]
+    fn test_partition_with_one_side_empty() {
+        let (matched, unmatched): (Vec<u64>, Vec<u64>) =
+            vec![1, 2, 3].into_iter().partition_with(|u: &u64| *u > 5);
+
+        assert!(matched.is_empty());
+        assert_eq!(unmatched, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_count_with() {
+        let f = |u: &u64| *u > 65;
+
+        assert_eq!((0..100).count_with(f), (0..100).filter_with(f).count());
+        assert_eq!((0..100).count_with(f), 34);
+        assert_eq!((0..0).count_with(f), 0);
+    }
+
+    fn panics_after(limit: u64) -> impl Iterator<Item = u64> {
+        (0..).inspect(move |&u| {
+            if u > limit {
+                panic!("evaluated {} after short-circuit", u);
+            }
+        })
+    }
+
+    #[test]
+    fn test_any_with() {
+        assert!(vec![1, 7, 3].into_iter().any_with(|u: &u64| *u > 5));
+        assert!(!vec![1, 2, 3].into_iter().any_with(|u: &u64| *u > 5));
+        assert!(!Vec::new().into_iter().any_with(|u: &u64| *u > 5));
+    }
+
+    #[test]
+    fn test_any_with_short_circuits() {
+        assert!(panics_after(6).any_with(|u: &u64| *u > 5));
+    }
+
+    #[test]
+    fn test_all_with() {
+        assert!(vec![6, 7, 8].into_iter().all_with(|u: &u64| *u > 5));
+        assert!(!vec![6, 2, 8].into_iter().all_with(|u: &u64| *u > 5));
+        assert!(Vec::new().into_iter().all_with(|u: &u64| *u > 5));
+    }
+
+    #[test]
+    fn test_all_with_short_circuits() {
+        assert!(!panics_after(6).all_with(|u: &u64| *u < 6));
+    }
+
+    #[test]
+    fn test_find_with() {
+        let mut iter = vec![1, 7, 3, 9, 2].into_iter();
+
+        assert_eq!(iter.find_with(|u: &u64| *u > 5), Some(7));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.find_with(|u: &u64| *u > 5), Some(9));
+        assert_eq!(iter.find_with(|u: &u64| *u > 5), None);
+    }
+
+    #[test]
+    fn test_find_with_stops_at_match() {
+        assert_eq!(panics_after(6).find_with(|u: &u64| *u > 5), Some(6));
+    }
+
+    #[test]
+    fn test_position_with() {
+        let mut iter = vec![1, 7, 3, 9, 2].into_iter();
+
+        assert_eq!(iter.position_with(|u: &u64| *u > 5), Some(1));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.position_with(|u: &u64| *u > 5), Some(0));
+        assert_eq!(iter.position_with(|u: &u64| *u > 5), None);
+    }
+
+    #[test]
+    fn test_position_with_stops_at_match() {
+        assert_eq!(panics_after(6).position_with(|u: &u64| *u > 5), Some(6));
+    }
+
+    #[test]
+    fn test_take_while_with() {
+        let f = |u: &usize| *u < 5;
+
+        let v: Vec<usize> = vec![1, 2, 7, 3, 9].into_iter().take_while_with(f).collect();
+        assert_eq!(v, vec![1, 2]);
+
+        let v: Vec<usize> = vec![7, 1, 2].into_iter().take_while_with(f).collect();
+        assert!(v.is_empty());
+
+        let v: Vec<usize> = vec![1, 2, 3].into_iter().take_while_with(f).collect();
+        assert_eq!(v, vec![1, 2, 3]);
+
+        let v: Vec<usize> = Vec::new().into_iter().take_while_with(f).collect();
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn test_take_while_with_stops_at_rejection() {
+        let f = |u: &usize| *u < 5;
+        let mut iter = vec![1, 7, 2, 3].into_iter().take_while_with(f);
+
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_skip_while_with() {
+        let f = |u: &usize| *u < 5;
+
+        let v: Vec<usize> = vec![1, 2, 7, 3, 9].into_iter().skip_while_with(f).collect();
+        assert_eq!(v, vec![7, 3, 9]);
+
+        let v: Vec<usize> = vec![7, 1, 2].into_iter().skip_while_with(f).collect();
+        assert_eq!(v, vec![7, 1, 2]);
+
+        let v: Vec<usize> = vec![1, 2, 3].into_iter().skip_while_with(f).collect();
+        assert!(v.is_empty());
+
+        let v: Vec<usize> = Vec::new().into_iter().skip_while_with(f).collect();
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn test_take_while_with_size_hint_and_fused() {
+        fn assert_fused<I: FusedIterator>(_: &I) {}
+
+        let mut iter = vec![1, 2, 7, 3]
+            .into_iter()
+            .take_while_with(|u: &usize| *u < 5);
+        assert_fused(&iter);
+        assert_eq!(iter.size_hint(), (0, Some(4)));
+
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.size_hint(), (0, Some(3)));
+
+        assert_eq!(iter.by_ref().count(), 1);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_skip_while_with_size_hint_and_fused() {
+        fn assert_fused<I: FusedIterator>(_: &I) {}
+
+        let mut iter = vec![1, 2, 7, 3]
+            .into_iter()
+            .skip_while_with(|u: &usize| *u < 5);
+        assert_fused(&iter);
+        assert_eq!(iter.size_hint(), (0, Some(4)));
+
+        assert_eq!(iter.next(), Some(7));
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+
+        assert_eq!(iter.by_ref().count(), 1);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    fn entries() -> Vec<(String, u64)> {
+        vec![
+            (String::from("a"), 3),
+            (String::from("bb"), 12),
+            (String::from("c"), 7),
+            (String::from("dd"), 20),
         ]
-        .into_iter()
-        .filter_errs(f)
-        .collect();
+    }
 
-        assert_eq!(v, vec![Ok(1), Ok(3), Ok(5), Err(6), Ok(7), Err(8), Ok(9)]);
+    #[test]
+    fn test_filter_keys() {
+        let v: Vec<(String, u64)> = entries()
+            .into_iter()
+            .filter_keys(|k: &String| k.len() == 1)
+            .collect();
+
+        assert_eq!(v, vec![(String::from("a"), 3), (String::from("c"), 7)]);
+    }
+
+    #[test]
+    fn test_filter_values() {
+        use crate::ops::range::in_range;
+
+        let v: Vec<(String, u64)> = entries()
+            .into_iter()
+            .filter_values(in_range(5..=15))
+            .collect();
+
+        assert_eq!(v, vec![(String::from("bb"), 12), (String::from("c"), 7)]);
+    }
+
+    #[test]
+    fn test_filter_map_entries() {
+        use std::collections::BTreeMap;
+
+        let map: BTreeMap<String, u64> = entries().into_iter().collect();
+        let keys: Vec<&String> = map
+            .iter()
+            .filter_values(|&&v: &&u64| v > 5)
+            .map(|(k, _)| k)
+            .collect();
+
+        assert_eq!(keys, vec!["bb", "c", "dd"]);
+    }
+
+    #[test]
+    fn test_dedup_by_verdict() {
+        use crate::ops::cmp::gt;
+
+        let v: Vec<u64> = vec![1, 2, 8, 9, 3, 4]
+            .into_iter()
+            .dedup_by_verdict(gt(5))
+            .collect();
+        assert_eq!(v, vec![1, 8, 3]);
+
+        let v: Vec<u64> = Vec::new().into_iter().dedup_by_verdict(gt(5)).collect();
+        assert!(v.is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_windows_with() {
+        let increasing = |w: &&[u64]| w.windows(2).all(|p| p[0] < p[1]);
+        let v: Vec<u64> = vec![1, 2, 3, 2, 4, 5, 6, 1]
+            .into_iter()
+            .windows_with(3, increasing)
+            .collect();
+
+        assert_eq!(v, vec![1, 2, 4]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_windows_with_short_input() {
+        let v: Vec<u64> = vec![1, 2]
+            .into_iter()
+            .windows_with(3, |_: &&[u64]| true)
+            .collect();
+        assert!(v.is_empty());
+
+        let v: Vec<u64> = vec![1, 2, 3]
+            .into_iter()
+            .windows_with(3, |_: &&[u64]| true)
+            .collect();
+        assert_eq!(v, vec![1]);
+
+        let v: Vec<u64> = vec![1, 2, 3]
+            .into_iter()
+            .windows_with(usize::MAX, |_: &&[u64]| true)
+            .collect();
+        assert!(v.is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn test_windows_with_zero_size() {
+        let _ = vec![1u64].into_iter().windows_with(0, |_: &&[u64]| true);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_chunks_with() {
+        let sum_at_least_7 = |c: &Vec<u64>| c.iter().sum::<u64>() >= 7;
+        let v: Vec<Vec<u64>> = vec![1, 2, 3, 4, 5, 1, 6, 0, 8]
+            .into_iter()
+            .chunks_with(2, sum_at_least_7)
+            .collect();
+
+        assert_eq!(v, vec![vec![3, 4], vec![8]]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_chunks_with_short_last_chunk() {
+        let v: Vec<Vec<u64>> = vec![1, 2, 3]
+            .into_iter()
+            .chunks_with(2, |_: &Vec<u64>| true)
+            .collect();
+        assert_eq!(v, vec![vec![1, 2], vec![3]]);
+
+        let v: Vec<Vec<u64>> = Vec::new()
+            .into_iter()
+            .chunks_with(2, |_: &Vec<u64>| true)
+            .collect();
+        assert!(v.is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_retain_with() {
+        use crate::ops::range::in_range;
+
+        let mut v: Vec<u64> = vec![1, 4, 9, 5, 12, 7, 3];
+        v.retain_with(&in_range(4..=9));
+
+        assert_eq!(v, vec![4, 9, 5, 7]);
+    }
+
+    fn fails_on_zero(&a: &u64) -> Result<bool, u64> {
+        if a == 0 {
+            Err(a)
+        } else {
+            Ok(a > 5)
+        }
+    }
+
+    #[test]
+    fn test_try_any_with() {
+        assert_eq!(
+            vec![1, 2, 7].into_iter().try_any_with(fails_on_zero),
+            Ok(true)
+        );
+        assert_eq!(
+            vec![1, 2, 3].into_iter().try_any_with(fails_on_zero),
+            Ok(false)
+        );
+        assert_eq!(
+            vec![1, 0, 7].into_iter().try_any_with(fails_on_zero),
+            Err(0)
+        );
+        assert_eq!(
+            Vec::new().into_iter().try_any_with(fails_on_zero),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn test_try_any_with_short_circuits() {
+        use core::cell::Cell;
+
+        let calls = Cell::new(0);
+        let f = |&a: &u64| -> Result<bool, u64> {
+            calls.set(calls.get() + 1);
+            fails_on_zero(&a)
+        };
+
+        assert_eq!(vec![1, 7, 0, 9].into_iter().try_any_with(&f), Ok(true));
+        assert_eq!(calls.get(), 2);
+
+        calls.set(0);
+        assert_eq!(vec![1, 0, 7, 9].into_iter().try_any_with(&f), Err(0));
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_try_all_with() {
+        assert_eq!(
+            vec![6, 7, 8].into_iter().try_all_with(fails_on_zero),
+            Ok(true)
+        );
+        assert_eq!(
+            vec![6, 2, 8].into_iter().try_all_with(fails_on_zero),
+            Ok(false)
+        );
+        assert_eq!(
+            vec![6, 0, 8].into_iter().try_all_with(fails_on_zero),
+            Err(0)
+        );
+        assert_eq!(Vec::new().into_iter().try_all_with(fails_on_zero), Ok(true));
+    }
+
+    #[test]
+    fn test_try_all_with_short_circuits() {
+        use core::cell::Cell;
+
+        let calls = Cell::new(0);
+        let f = |&a: &u64| -> Result<bool, u64> {
+            calls.set(calls.get() + 1);
+            fails_on_zero(&a)
+        };
+
+        assert_eq!(vec![6, 2, 0, 8].into_iter().try_all_with(&f), Ok(false));
+        assert_eq!(calls.get(), 2);
+
+        calls.set(0);
+        assert_eq!(vec![6, 0, 2, 8].into_iter().try_all_with(&f), Err(0));
+        assert_eq!(calls.get(), 2);
     }
 }
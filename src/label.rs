@@ -0,0 +1,113 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Labeling items with filters
+//!
+//! A `LabeledFilter` returns a label for an item instead of just a boolean decision. This can be
+//! used to route items into classes, reusing the composition of ordinary filters:
+//!
+//! ```
+//! use filters::filter::Filter;
+//! use filters::label::{first_label, LabeledFilter};
+//!
+//! let size = first_label(vec![
+//!     (Box::new(|&a: &usize| a < 10) as Box<dyn Filter<usize>>, "small"),
+//!     (Box::new((|&a: &usize| a >= 10).and(|&a: &usize| a < 100)), "medium"),
+//! ]);
+//!
+//! assert_eq!(size.label(&3), Some("small"));
+//! assert_eq!(size.label(&42), Some("medium"));
+//! assert_eq!(size.label(&1000), None);
+//! ```
+//!
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::filter::Filter;
+
+/// Trait for filters which assign a label to an item
+pub trait LabeledFilter<N, L> {
+    /// The label for the item, or `None` if it does not get one
+    fn label(&self, _: &N) -> Option<L>;
+}
+
+/// All closures that take a ref to something and return `Option<L>` are labeled filters
+impl<I, L, T> LabeledFilter<I, L> for T
+where
+    T: Fn(&I) -> Option<L>,
+{
+    fn label(&self, other: &I) -> Option<L> {
+        self(other)
+    }
+}
+
+/// Labeled filter which returns the label of the first matching filter
+pub struct FirstLabel<N, L>(Vec<(Box<dyn Filter<N>>, L)>);
+
+impl<N, L> FirstLabel<N, L> {
+    pub fn new(filters: Vec<(Box<dyn Filter<N>>, L)>) -> FirstLabel<N, L> {
+        FirstLabel(filters)
+    }
+}
+
+impl<N, L: Clone> LabeledFilter<N, L> for FirstLabel<N, L> {
+    fn label(&self, e: &N) -> Option<L> {
+        self.0
+            .iter()
+            .find(|(f, _)| f.filter(e))
+            .map(|(_, l)| l.clone())
+    }
+}
+
+/// Create a labeled filter which returns the label of the first matching filter
+///
+/// The filters are tried in order, so later filters only see items earlier ones rejected.
+pub fn first_label<N, L>(filters_with_labels: Vec<(Box<dyn Filter<N>>, L)>) -> FirstLabel<N, L> {
+    FirstLabel::new(filters_with_labels)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sizes() -> FirstLabel<usize, &'static str> {
+        first_label(vec![
+            (Box::new(|&a: &usize| a < 10), "small"),
+            (Box::new(|&a: &usize| a < 100), "medium"),
+            (Box::new(|_: &usize| true), "large"),
+        ])
+    }
+
+    #[test]
+    fn routes_in_order() {
+        let s = sizes();
+
+        assert_eq!(s.label(&0), Some("small"));
+        assert_eq!(s.label(&9), Some("small"));
+        assert_eq!(s.label(&10), Some("medium"));
+        assert_eq!(s.label(&99), Some("medium"));
+        assert_eq!(s.label(&100), Some("large"));
+    }
+
+    #[test]
+    fn no_match() {
+        let s = first_label(vec![(
+            Box::new(|&a: &usize| a % 2 == 0) as Box<dyn Filter<usize>>,
+            "even",
+        )]);
+
+        assert_eq!(s.label(&2), Some("even"));
+        assert_eq!(s.label(&3), None);
+    }
+
+    #[test]
+    fn closures() {
+        let l = |&a: &usize| if a > 5 { Some(a * 2) } else { None };
+
+        assert_eq!(l.label(&6), Some(12));
+        assert_eq!(l.label(&5), None);
+    }
+}
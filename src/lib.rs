@@ -31,7 +31,39 @@
 //!  * Not
 //!
 //! others are build by combining them. The operators can be used from the filter instance
-//! directly, as shown in the example above.
+//! directly, as shown in the example above. The filter types shipped with this library can also
+//! be combined with `&`, `|` and `^`.
+//!
+//! # Debugging
+//!
+//! The filter types shipped with this library implement `Debug` if all their parts do, so a
+//! filter tree built from them can be printed. Closures do not implement `Debug`, so a tree
+//! containing a closure cannot be printed.
+//!
+//! ```
+//! use filters::filter::Filter;
+//! use filters::ops::cmp::{GreaterThan, LessThan};
+//!
+//! let a = GreaterThan(5).and(LessThan(15)).not();
+//! assert_eq!(format!("{:?}", a), "Not(And(GreaterThan(5), LessThan(15)))");
+//! ```
+//!
+//! # Features
+//!
+//! The library can be used without the standard library. The `std` feature is enabled by
+//! default; without it the crate is `#![no_std]` and only the filters which need neither
+//! allocation nor `std` are available (`And`, `Or`, `Not`, `XOr`, `Bool`, the comparison
+//! filters, ...). The `alloc` feature additionally enables the filters which need `Box`, `Vec` or
+//! `String` (`All`, `Any`, `Threshold`, the string filters, boxed filters, ...). Filters which
+//! need hashing, time or panic handling require `std`.
+//!
+//! The `async` feature adds the `asynchronous` module with the `FilterAsync` trait for filters
+//! which decide asynchronously, and an adapter to filter `futures` streams with them. The `rayon`
+//! feature adds the `par_iter` module to filter rayon parallel iterators.
+//!
+//! The `serde` feature adds the `serde_expr` module with `FilterExpr`, a filter tree which can be
+//! serialized. The `expr` feature adds `ops::expr`, which compiles a boolean expression from a
+//! string into a filter at runtime.
 //!
 //! # Implementing own filters
 //!
@@ -72,14 +104,90 @@
 //! ```
 
 #![doc(html_root_url = "https://matthiasbeyer.github.io/filters/")]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![cfg_attr(
     feature = "unstable-filter-as-fn",
     feature(unboxed_closures, fn_traits)
 )]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[macro_use]
 pub mod impl_traits;
+#[cfg(feature = "async")]
+pub mod asynchronous;
 pub mod failable;
 pub mod filter;
 pub mod iter;
+#[cfg(feature = "alloc")]
+pub mod label;
 pub mod ops;
+#[cfg(feature = "rayon")]
+pub mod par_iter;
+#[cfg(feature = "serde")]
+pub mod serde_expr;
+#[cfg(feature = "alloc")]
+pub mod sql;
+#[cfg(test)]
+mod test_util;
+
+pub use crate::ops::from_fn::from_fn;
+
+/// Build a tree of `And` or `Or` filters from a list of filters
+///
+/// `filter!(all: a, b, c)` builds `a.and(b).and(c)`, `filter!(any: a, b, c)` builds
+/// `a.or(b).or(c)`. Invocations can be nested to build deeper trees.
+///
+/// ```
+/// use filters::filter;
+/// use filters::filter::Filter;
+///
+/// let f = filter!(all:
+///     |&a: &usize| a > 5,
+///     |&a: &usize| a < 50,
+///     |&a: &usize| a % 2 == 0,
+///     |&a: &usize| a != 10,
+///     filter!(any: |&a: &usize| a < 20, |&a: &usize| a > 40),
+/// );
+///
+/// let r: Vec<usize> = (0..60).filter(|a| f.filter(a)).collect();
+/// assert_eq!(r, vec![6, 8, 12, 14, 16, 18, 42, 44, 46, 48]);
+/// ```
+#[macro_export]
+macro_rules! filter {
+    (all: $first:expr $(, $rest:expr)* $(,)?) => {{
+        let f = $first;
+        $( let f = $crate::ops::and::And::new(f, $rest); )*
+        f
+    }};
+    (any: $first:expr $(, $rest:expr)* $(,)?) => {{
+        let f = $first;
+        $( let f = $crate::ops::or::Or::new(f, $rest); )*
+        f
+    }};
+}
+
+/// Compile check that the core filters work without `std`
+#[cfg(not(feature = "std"))]
+#[allow(dead_code)]
+mod no_std_check {
+    use crate::failable::filter::FailableFilter;
+    use crate::filter::Filter;
+    use crate::ops::bool::Bool;
+    use crate::ops::cmp::{GreaterThan, LessThan};
+
+    fn combinators() -> bool {
+        let f = GreaterThan(5u8)
+            .and(LessThan(15))
+            .or(Bool::new(false))
+            .xor(|&a: &u8| a == 10)
+            .not();
+        f.filter(&7)
+    }
+
+    fn failable() -> Result<bool, ()> {
+        let f = (|&a: &u8| -> Result<bool, ()> { Ok(a > 5) }).not();
+        f.filter(&7)
+    }
+}
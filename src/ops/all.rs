@@ -0,0 +1,71 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! All implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::filter::Filter;
+
+/// Filter which passes if all of a runtime collection of filters pass
+///
+/// An empty `All` always passes.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+pub struct All<N>(Vec<Box<dyn Filter<N>>>);
+
+impl<N> All<N> {
+    pub fn new(filters: Vec<Box<dyn Filter<N>>>) -> All<N> {
+        All(filters)
+    }
+}
+
+impl_boxed_debug!(All, N; filters: 0);
+
+impl<N> Filter<N> for All<N> {
+    fn filter(&self, e: &N) -> bool {
+        self.0.iter().all(|f| f.filter(e))
+    }
+}
+
+/// Create a filter which passes if all of the `filters` pass
+pub fn all<N>(filters: Vec<Box<dyn Filter<N>>>) -> All<N> {
+    All::new(filters)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn all_pass() {
+        let f = all(vec![
+            Box::new(|&a: &usize| a > 5),
+            Box::new(|&a: &usize| a % 2 == 0),
+        ]);
+
+        assert!(!f.filter(&4));
+        assert!(!f.filter(&7));
+        assert!(f.filter(&8));
+    }
+
+    #[test]
+    fn empty_passes() {
+        let f: All<usize> = all(Vec::new());
+        assert!(f.filter(&0));
+    }
+
+    #[test]
+    fn composes() {
+        let f = all(vec![Box::new(|&a: &usize| a > 5)]).and(|&a: &usize| a < 10);
+
+        assert!(f.filter(&7));
+        assert!(!f.filter(&12));
+    }
+}
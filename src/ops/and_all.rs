@@ -0,0 +1,64 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! AND over a list of filters implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use alloc::vec::Vec;
+
+use crate::filter::Filter;
+
+/// Filter which passes if all of a list of same-typed filters pass
+///
+/// Evaluation stops at the first filter which does not pass. An empty `AndAll` always passes.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct AndAll<F>(Vec<F>);
+
+impl<F> AndAll<F> {
+    pub fn new(filters: Vec<F>) -> AndAll<F> {
+        AndAll(filters)
+    }
+}
+
+impl_operators!(AndAll, self e { self.0.iter().all(|f| f.filter(e)) }, F);
+impl_bit_operators!(AndAll, F);
+impl_not_operator!(AndAll, F);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::EqTo;
+
+    #[test]
+    fn and_all() {
+        let f = EqTo { i: 3 }.and_all(vec![EqTo { i: 3 }, EqTo { i: 3 }]);
+        assert!(f.filter(&3));
+        assert!(!f.filter(&4));
+
+        let f = EqTo { i: 3 }.and_all(vec![EqTo { i: 3 }, EqTo { i: 4 }]);
+        assert!(!f.filter(&3));
+        assert!(!f.filter(&4));
+    }
+
+    #[test]
+    fn empty_passes() {
+        let f: AndAll<EqTo> = AndAll::new(Vec::new());
+        assert!(f.filter(&0));
+    }
+
+    #[test]
+    fn short_circuits() {
+        let a: fn(&usize) -> bool = |&a| a > 5;
+        let b: fn(&usize) -> bool = |&a| a < 10;
+        let c: fn(&usize) -> bool = |_| panic!("must not be evaluated");
+
+        let f = a.and_all(vec![b, c]);
+        assert!(!f.filter(&12));
+    }
+}
@@ -0,0 +1,71 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Any implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::filter::Filter;
+
+/// Filter which passes if any of a runtime collection of filters pass
+///
+/// An empty `Any` never passes.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+pub struct Any<N>(Vec<Box<dyn Filter<N>>>);
+
+impl<N> Any<N> {
+    pub fn new(filters: Vec<Box<dyn Filter<N>>>) -> Any<N> {
+        Any(filters)
+    }
+}
+
+impl_boxed_debug!(Any, N; filters: 0);
+
+impl<N> Filter<N> for Any<N> {
+    fn filter(&self, e: &N) -> bool {
+        self.0.iter().any(|f| f.filter(e))
+    }
+}
+
+/// Create a filter which passes if any of the `filters` pass
+pub fn any<N>(filters: Vec<Box<dyn Filter<N>>>) -> Any<N> {
+    Any::new(filters)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn any_passes() {
+        let f = any(vec![
+            Box::new(|&a: &usize| a > 5),
+            Box::new(|&a: &usize| a % 2 == 0),
+        ]);
+
+        assert!(!f.filter(&3));
+        assert!(f.filter(&4));
+        assert!(f.filter(&7));
+    }
+
+    #[test]
+    fn empty_fails() {
+        let f: Any<usize> = any(Vec::new());
+        assert!(!f.filter(&0));
+    }
+
+    #[test]
+    fn composes() {
+        let f = any(vec![Box::new(|&a: &usize| a > 5)]).not();
+
+        assert!(f.filter(&3));
+        assert!(!f.filter(&7));
+    }
+}
@@ -9,18 +9,30 @@
 //! Will be automatically included when including `filter::Filter`, so importing this module
 //! shouldn't be necessary.
 //!
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
 use crate::filter::Filter;
+#[cfg(feature = "alloc")]
+use crate::sql::{SqlLiteral, SqlRenderable};
 
 #[must_use = "filters are lazy and do nothing unless consumed"]
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Bool(bool);
 
 impl Bool {
-    pub fn new(b: bool) -> Bool {
+    pub const fn new(b: bool) -> Bool {
         Bool(b)
     }
 }
 
+/// The default `Bool` rejects everything
+impl Default for Bool {
+    fn default() -> Bool {
+        Bool::new(false)
+    }
+}
+
 impl From<bool> for Bool {
     fn from(b: bool) -> Bool {
         Bool::new(b)
@@ -28,3 +40,139 @@ impl From<bool> for Bool {
 }
 
 impl_operators!(Bool, self e { self.0 }, );
+impl_bit_operators!(Bool,);
+impl_not_operator!(Bool,);
+
+/// A plain `bool` can be used as a filter which ignores its input, like `Bool`
+impl<N> Filter<N> for bool {
+    fn filter(&self, _: &N) -> bool {
+        *self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl SqlRenderable for Bool {
+    fn to_sql(&self, _: &str) -> String {
+        self.0.to_sql_literal()
+    }
+}
+
+/// Filter which accepts everything
+///
+/// Identity element for `and()` chains and absorbing element for `or()` chains.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct Always;
+
+impl_operators!(Always, self e { true }, );
+impl_bit_operators!(Always,);
+impl_not_operator!(Always,);
+
+#[cfg(feature = "alloc")]
+impl SqlRenderable for Always {
+    fn to_sql(&self, _: &str) -> String {
+        true.to_sql_literal()
+    }
+}
+
+/// Filter which accepts nothing
+///
+/// Identity element for `or()` chains and absorbing element for `and()` chains.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct Never;
+
+impl_operators!(Never, self e { false }, );
+impl_bit_operators!(Never,);
+impl_not_operator!(Never,);
+
+#[cfg(feature = "alloc")]
+impl SqlRenderable for Never {
+    fn to_sql(&self, _: &str) -> String {
+        false.to_sql_literal()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn always_and_never() {
+        assert!(Always.filter(&1));
+        assert!(Always.filter(&"a"));
+        assert!(!Never.filter(&1));
+        assert!(!Never.filter(&"a"));
+    }
+
+    #[test]
+    fn identity_elements() {
+        let f = |&u: &usize| u > 5;
+
+        for u in 0..10 {
+            assert_eq!(Always.and(f).filter(&u), f.filter(&u));
+            assert_eq!(Never.or(f).filter(&u), f.filter(&u));
+        }
+    }
+
+    #[test]
+    fn absorbing_elements() {
+        let f = |&u: &usize| u > 5;
+
+        for u in 0..10 {
+            assert!(Always.or(f).filter(&u));
+            assert!(!Never.and(f).filter(&u));
+        }
+    }
+
+    #[test]
+    fn bool_eq_and_default() {
+        assert_eq!(Bool::new(true), Bool::from(true));
+        assert_ne!(Bool::new(true), Bool::new(false));
+        assert_eq!(Bool::default(), Bool::new(false));
+        assert!(!Bool::default().filter(&0));
+    }
+
+    #[test]
+    fn plain_bool() {
+        let f = |&u: &usize| u > 5;
+        let enabled = true;
+        let disabled = false;
+
+        let v: Vec<usize> = (0..10).filter(|u| f.and(enabled).filter(u)).collect();
+        assert_eq!(v, vec![6, 7, 8, 9]);
+
+        let v: Vec<usize> = (0..10).filter(|u| f.and(disabled).filter(u)).collect();
+        assert_eq!(v, Vec::<usize>::new());
+
+        assert!(true.filter(&"a"));
+        assert!(!false.or(f).filter(&1));
+    }
+
+    #[test]
+    fn const_filter() {
+        use crate::ops::and::And;
+        use crate::ops::not::Not;
+        use crate::ops::or::Or;
+        use crate::ops::xnor::XNor;
+        use crate::ops::xor::XOr;
+
+        const F: Or<And<Bool, Not<Bool>>, XOr<Bool, Bool>> = Or::new(
+            And::new(Bool::new(true), Not::new(Bool::new(false))),
+            XOr::new(Bool::new(false), Bool::new(false)),
+        );
+
+        assert!(F.filter(&1));
+        assert!(F.filter(&"a"));
+
+        const G: XNor<Bool, Bool> = XNor::new(Bool::new(false), Bool::new(false));
+
+        assert!(G.filter(&1));
+    }
+
+    #[test]
+    fn zero_sized() {
+        assert_eq!(std::mem::size_of::<Always>(), 0);
+        assert_eq!(std::mem::size_of::<Never>(), 0);
+    }
+}
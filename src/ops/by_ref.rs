@@ -0,0 +1,35 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Borrowing filter implementation.
+//!
+//! A blanket `impl Filter<N> for &F` would conflict with the implementation for closures (a
+//! reference to a closure is a closure itself), so borrowed filters are wrapped in `ByRef`
+//! instead.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use crate::filter::Filter;
+
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct ByRef<'a, F: 'a + ?Sized>(&'a F);
+
+impl<'a, F: 'a + ?Sized> ByRef<'a, F> {
+    pub fn new(a: &'a F) -> ByRef<'a, F> {
+        ByRef(a)
+    }
+}
+
+impl<'a, F, N> Filter<N> for ByRef<'a, F>
+where
+    F: Filter<N> + 'a + ?Sized,
+{
+    fn filter(&self, e: &N) -> bool {
+        self.0.filter(e)
+    }
+}
@@ -0,0 +1,91 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Memoization implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::filter::Filter;
+
+/// Filter which remembers the decision of the inner filter for every input it has seen
+///
+/// The cache lives in a `RefCell`, so it is filled through a shared reference. This makes
+/// `Cached` not `Sync`, it cannot be shared between threads. The cache is never evicted, so it
+/// grows with every distinct input. Cloning a `Cached` clones the cache as well.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct Cached<F, N>(F, RefCell<HashMap<N, bool>>);
+
+impl<F, N> Cached<F, N>
+where
+    N: Eq + Hash,
+{
+    pub fn new(a: F) -> Cached<F, N> {
+        Cached(a, RefCell::new(HashMap::new()))
+    }
+}
+
+impl<F, N> Filter<N> for Cached<F, N>
+where
+    F: Filter<N>,
+    N: Eq + Hash + Clone,
+{
+    fn filter(&self, e: &N) -> bool {
+        if let Some(&decision) = self.1.borrow().get(e) {
+            return decision;
+        }
+
+        let decision = self.0.filter(e);
+        self.1.borrow_mut().insert(e.clone(), decision);
+        decision
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell;
+
+    use crate::filter::Filter;
+
+    #[test]
+    fn inner_filter_runs_once_per_input() {
+        let calls = Cell::new(0);
+        let f = (|&a: &usize| {
+            calls.set(calls.get() + 1);
+            a > 5
+        })
+        .cached();
+
+        let r: Vec<usize> = vec![1, 7, 1, 7, 3, 7, 1]
+            .into_iter()
+            .filter(|a| f.filter(a))
+            .collect();
+
+        assert_eq!(r, vec![7, 7, 7]);
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn composes_with_other_filters() {
+        let calls = Cell::new(0);
+        let f = (|&a: &usize| {
+            calls.set(calls.get() + 1);
+            a > 5
+        })
+        .cached()
+        .not();
+
+        assert!(f.filter(&3));
+        assert!(!f.filter(&7));
+        assert!(f.filter(&3));
+        assert_eq!(calls.get(), 2);
+    }
+}
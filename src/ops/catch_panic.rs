@@ -0,0 +1,71 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Panic catching implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use std::panic::{self, RefUnwindSafe};
+
+use crate::filter::Filter;
+
+/// Filter which returns a default decision if the inner filter panics
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct CatchPanic<T>(T, bool);
+
+impl<T> CatchPanic<T> {
+    pub fn new(a: T, default: bool) -> CatchPanic<T> {
+        CatchPanic(a, default)
+    }
+}
+
+impl<N, T> Filter<N> for CatchPanic<T>
+where
+    N: RefUnwindSafe,
+    T: Filter<N> + RefUnwindSafe,
+{
+    fn filter(&self, e: &N) -> bool {
+        panic::catch_unwind(|| self.0.filter(e)).unwrap_or(self.1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::Filter;
+    use crate::iter::FilterWith;
+
+    fn panics_on_three(a: &usize) -> bool {
+        if *a == 3 {
+            panic!("three");
+        }
+        *a > 1
+    }
+
+    #[test]
+    fn panic_returns_default() {
+        assert!(panics_on_three.catch_panic(true).filter(&3));
+        assert!(!panics_on_three.catch_panic(false).filter(&3));
+    }
+
+    #[test]
+    fn no_panic_returns_inner_decision() {
+        let f = panics_on_three.catch_panic(true);
+        assert!(!f.filter(&0));
+        assert!(f.filter(&2));
+    }
+
+    #[test]
+    fn iteration_continues_after_panic() {
+        let v: Vec<usize> = vec![0, 1, 2, 3, 4, 5]
+            .into_iter()
+            .filter_with(panics_on_three.catch_panic(false))
+            .collect();
+
+        assert_eq!(v, vec![2, 4, 5]);
+    }
+}
@@ -0,0 +1,190 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Comparison filter implementations.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::filter::Filter;
+#[cfg(feature = "alloc")]
+use crate::sql::{SqlLiteral, SqlRenderable};
+
+/// Filter which accepts values greater than the bound
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct GreaterThan<T>(pub T);
+
+impl_bit_operators!(GreaterThan, T);
+impl_not_operator!(GreaterThan, T);
+
+impl<T: PartialOrd> Filter<T> for GreaterThan<T> {
+    fn filter(&self, e: &T) -> bool {
+        *e > self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: SqlLiteral> SqlRenderable for GreaterThan<T> {
+    fn to_sql(&self, column: &str) -> String {
+        format!("{} > {}", column, self.0.to_sql_literal())
+    }
+}
+
+/// Filter which accepts values greater than or equal to the bound
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct GreaterEq<T>(pub T);
+
+impl_bit_operators!(GreaterEq, T);
+impl_not_operator!(GreaterEq, T);
+
+impl<T: PartialOrd> Filter<T> for GreaterEq<T> {
+    fn filter(&self, e: &T) -> bool {
+        *e >= self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: SqlLiteral> SqlRenderable for GreaterEq<T> {
+    fn to_sql(&self, column: &str) -> String {
+        format!("{} >= {}", column, self.0.to_sql_literal())
+    }
+}
+
+/// Filter which accepts values less than the bound
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct LessThan<T>(pub T);
+
+impl_bit_operators!(LessThan, T);
+impl_not_operator!(LessThan, T);
+
+impl<T: PartialOrd> Filter<T> for LessThan<T> {
+    fn filter(&self, e: &T) -> bool {
+        *e < self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: SqlLiteral> SqlRenderable for LessThan<T> {
+    fn to_sql(&self, column: &str) -> String {
+        format!("{} < {}", column, self.0.to_sql_literal())
+    }
+}
+
+/// Filter which accepts values less than or equal to the bound
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct LessEq<T>(pub T);
+
+impl_bit_operators!(LessEq, T);
+impl_not_operator!(LessEq, T);
+
+impl<T: PartialOrd> Filter<T> for LessEq<T> {
+    fn filter(&self, e: &T) -> bool {
+        *e <= self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: SqlLiteral> SqlRenderable for LessEq<T> {
+    fn to_sql(&self, column: &str) -> String {
+        format!("{} <= {}", column, self.0.to_sql_literal())
+    }
+}
+
+/// Filter which accepts values equal to the bound
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct Equal<T>(pub T);
+
+impl_bit_operators!(Equal, T);
+impl_not_operator!(Equal, T);
+
+impl<T: PartialEq> Filter<T> for Equal<T> {
+    fn filter(&self, e: &T) -> bool {
+        *e == self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: SqlLiteral> SqlRenderable for Equal<T> {
+    fn to_sql(&self, column: &str) -> String {
+        format!("{} = {}", column, self.0.to_sql_literal())
+    }
+}
+
+/// Create a filter which accepts values greater than `bound`
+pub fn gt<T: PartialOrd>(bound: T) -> GreaterThan<T> {
+    GreaterThan(bound)
+}
+
+/// Create a filter which accepts values greater than or equal to `bound`
+pub fn ge<T: PartialOrd>(bound: T) -> GreaterEq<T> {
+    GreaterEq(bound)
+}
+
+/// Create a filter which accepts values less than `bound`
+pub fn lt<T: PartialOrd>(bound: T) -> LessThan<T> {
+    LessThan(bound)
+}
+
+/// Create a filter which accepts values less than or equal to `bound`
+pub fn le<T: PartialOrd>(bound: T) -> LessEq<T> {
+    LessEq(bound)
+}
+
+/// Create a filter which accepts values equal to `bound`
+pub fn eq<T: PartialEq>(bound: T) -> Equal<T> {
+    Equal(bound)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn comparisons() {
+        assert!(gt(5).filter(&6));
+        assert!(!gt(5).filter(&5));
+        assert!(ge(5).filter(&5));
+        assert!(!ge(5).filter(&4));
+        assert!(lt(5).filter(&4));
+        assert!(!lt(5).filter(&5));
+        assert!(le(5).filter(&5));
+        assert!(!le(5).filter(&6));
+        assert!(eq(5).filter(&5));
+        assert!(!eq(5).filter(&6));
+    }
+
+    #[test]
+    fn inrange() {
+        let v: Vec<usize> = (0..21).collect();
+        let inrange = gt(5).and(lt(15));
+        let r: Vec<usize> = v.into_iter().filter(|x| inrange.filter(x)).collect();
+        assert_eq!(r, vec![6, 7, 8, 9, 10, 11, 12, 13, 14]);
+    }
+
+    #[test]
+    fn inrange_inclusive() {
+        let v: Vec<usize> = (0..21).collect();
+        let inrange = ge(5).and(le(15));
+        let r: Vec<usize> = v.into_iter().filter(|x| inrange.filter(x)).collect();
+        assert_eq!(r, vec![5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_sql() {
+        assert_eq!(ge(5).and(le(15)).to_sql("age"), "(age >= 5 AND age <= 15)");
+    }
+}
@@ -0,0 +1,82 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Counting implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use core::cell::Cell;
+
+use crate::filter::Filter;
+
+/// Filter which counts how often it was called and how often it passed
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct Counting<F> {
+    filter: F,
+    calls: Cell<usize>,
+    matches: Cell<usize>,
+}
+
+impl<F> Counting<F> {
+    pub fn new(filter: F) -> Counting<F> {
+        Counting {
+            filter,
+            calls: Cell::new(0),
+            matches: Cell::new(0),
+        }
+    }
+
+    /// The number of times the filter was called
+    pub fn calls(&self) -> usize {
+        self.calls.get()
+    }
+
+    /// The number of times the filter passed
+    pub fn matches(&self) -> usize {
+        self.matches.get()
+    }
+}
+
+impl<N, F: Filter<N>> Filter<N> for Counting<F> {
+    fn filter(&self, e: &N) -> bool {
+        let passed = self.filter.filter(e);
+        self.calls.set(self.calls.get() + 1);
+        if passed {
+            self.matches.set(self.matches.get() + 1);
+        }
+        passed
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::Filter;
+
+    #[test]
+    fn counts_calls_and_matches() {
+        let f = (|&a: &usize| a % 3 == 0).counting();
+
+        let r: Vec<usize> = (0..10).filter(|a| f.filter(a)).collect();
+
+        assert_eq!(r, vec![0, 3, 6, 9]);
+        assert_eq!(f.calls(), 10);
+        assert_eq!(f.matches(), 4);
+    }
+
+    #[test]
+    fn short_circuited_filters_are_not_counted() {
+        let inner = (|&a: &usize| a > 5).counting();
+        let f = (|&a: &usize| a < 3).or(inner.by_ref());
+
+        let r: Vec<usize> = (0..10).filter(|a| f.filter(a)).collect();
+
+        assert_eq!(r, vec![0, 1, 2, 6, 7, 8, 9]);
+        assert_eq!(inner.calls(), 7);
+        assert_eq!(inner.matches(), 4);
+    }
+}
@@ -0,0 +1,84 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Time based debouncing implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use crate::filter::Filter;
+
+/// Filter which accepts an item only after a quiet period
+///
+/// An item is accepted if at least the configured duration has passed since the last item was
+/// seen, whether that item was accepted or not. The first item is always accepted.
+///
+/// Unlike `RateLimit`, a steady stream of items arriving faster than the quiet period is
+/// rejected entirely.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct DebounceTime<C>(Duration, Cell<Option<Instant>>, C);
+
+impl<C> DebounceTime<C>
+where
+    C: Fn() -> Instant,
+{
+    /// Create a debouncer which reads the current time from `clock`
+    pub fn new(quiet: Duration, clock: C) -> DebounceTime<C> {
+        DebounceTime(quiet, Cell::new(None), clock)
+    }
+}
+
+impl DebounceTime<fn() -> Instant> {
+    /// Create a debouncer which reads the current time from `Instant::now()`
+    pub fn with_system_clock(quiet: Duration) -> DebounceTime<fn() -> Instant> {
+        DebounceTime::new(quiet, Instant::now)
+    }
+}
+
+impl<N, C> Filter<N> for DebounceTime<C>
+where
+    C: Fn() -> Instant,
+{
+    fn filter(&self, _: &N) -> bool {
+        let now = (self.2)();
+        self.1
+            .replace(Some(now))
+            .map(|last| now.saturating_duration_since(last) >= self.0)
+            .unwrap_or(true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn accepts_after_quiet_period() {
+        let start = Instant::now();
+        let now = Cell::new(start);
+        let debounce = DebounceTime::new(Duration::from_millis(100), || now.get());
+
+        // (offset of the item in ms, expected decision)
+        let events = [
+            (0, true),
+            (50, false),
+            (120, false),
+            (250, true),
+            (349, false),
+            (449, true),
+            (460, false),
+        ];
+
+        for &(offset, expected) in events.iter() {
+            now.set(start + Duration::from_millis(offset));
+            assert_eq!(debounce.filter(&offset), expected, "item at {}ms", offset);
+        }
+    }
+}
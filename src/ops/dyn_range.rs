@@ -0,0 +1,104 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Runtime configurable range implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use crate::filter::Filter;
+
+/// Filter which accepts values within a range whose bounds are configured at runtime
+///
+/// Each bound is a value and a flag whether the bound is inclusive. A missing bound means the
+/// range is unbounded on that side.
+///
+/// ```
+/// use filters::filter::Filter;
+/// use filters::ops::dyn_range::DynRange;
+///
+/// let inclusive = true;
+/// let r = DynRange::new(Some((5, inclusive)), Some((15, !inclusive)));
+///
+/// assert!(r.filter(&5));
+/// assert!(!r.filter(&15));
+/// ```
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct DynRange<T> {
+    low: Option<(T, bool)>,
+    high: Option<(T, bool)>,
+}
+
+impl<T> DynRange<T> {
+    pub fn new(low: Option<(T, bool)>, high: Option<(T, bool)>) -> DynRange<T> {
+        DynRange { low, high }
+    }
+}
+
+impl<T: PartialOrd> Filter<T> for DynRange<T> {
+    fn filter(&self, e: &T) -> bool {
+        let above_low = match self.low {
+            Some((ref low, true)) => e >= low,
+            Some((ref low, false)) => e > low,
+            None => true,
+        };
+
+        let below_high = match self.high {
+            Some((ref high, true)) => e <= high,
+            Some((ref high, false)) => e < high,
+            None => true,
+        };
+
+        above_low && below_high
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bound_inclusivity_combinations() {
+        for &low_inclusive in [true, false].iter() {
+            for &high_inclusive in [true, false].iter() {
+                let r = DynRange::new(Some((5, low_inclusive)), Some((10, high_inclusive)));
+
+                assert!(!r.filter(&4));
+                assert_eq!(r.filter(&5), low_inclusive);
+                assert!(r.filter(&7));
+                assert_eq!(r.filter(&10), high_inclusive);
+                assert!(!r.filter(&11));
+            }
+        }
+    }
+
+    #[test]
+    fn unbounded_low() {
+        let r = DynRange::new(None, Some((10, false)));
+
+        assert!(r.filter(&i64::MIN));
+        assert!(r.filter(&9));
+        assert!(!r.filter(&10));
+    }
+
+    #[test]
+    fn unbounded_high() {
+        let r = DynRange::new(Some((10, true)), None);
+
+        assert!(!r.filter(&9));
+        assert!(r.filter(&10));
+        assert!(r.filter(&i64::MAX));
+    }
+
+    #[test]
+    fn unbounded() {
+        let r: DynRange<f64> = DynRange::new(None, None);
+
+        assert!(r.filter(&f64::MIN));
+        assert!(r.filter(&f64::MAX));
+    }
+}
@@ -0,0 +1,90 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Email address filter implementation.
+//!
+//! Only available with the `email` feature.
+//!
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::filter::Filter;
+
+/// Filter which accepts strings that look like an email address
+///
+/// This is a pragmatic check, not a full RFC 5322 parser. A string is accepted if
+///
+///  * it contains exactly one `@`,
+///  * the local part before the `@` is not empty,
+///  * the domain after the `@` contains a `.`, with non-empty labels around every `.`,
+///  * and it contains no whitespace.
+///
+/// ```
+/// use filters::filter::Filter;
+/// use filters::ops::email::IsEmail;
+///
+/// assert!(IsEmail.filter(&"mail@example.com"));
+/// assert!(!IsEmail.filter(&"mail@example"));
+/// ```
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct IsEmail;
+
+impl IsEmail {
+    fn is_email(s: &str) -> bool {
+        if s.chars().any(char::is_whitespace) {
+            return false;
+        }
+
+        let mut parts = s.split('@');
+        let (local, domain) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(local), Some(domain), None) => (local, domain),
+            _ => return false,
+        };
+
+        !local.is_empty() && domain.contains('.') && domain.split('.').all(|l| !l.is_empty())
+    }
+}
+
+impl<'a> Filter<&'a str> for IsEmail {
+    fn filter(&self, e: &&'a str) -> bool {
+        IsEmail::is_email(e)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Filter<String> for IsEmail {
+    fn filter(&self, e: &String) -> bool {
+        IsEmail::is_email(e)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        assert!(IsEmail.filter(&"a@b.c"));
+        assert!(IsEmail.filter(&"first.last+tag@mail.example.org"));
+        assert!(IsEmail.filter(&String::from("mail@example.com")));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(!IsEmail.filter(&""));
+        assert!(!IsEmail.filter(&"a@b"));
+        assert!(!IsEmail.filter(&"@b.com"));
+        assert!(!IsEmail.filter(&"a@"));
+        assert!(!IsEmail.filter(&"a@@b.com"));
+        assert!(!IsEmail.filter(&"a@b@c.com"));
+        assert!(!IsEmail.filter(&"a@.com"));
+        assert!(!IsEmail.filter(&"a@b.com."));
+        assert!(!IsEmail.filter(&"a@b..com"));
+        assert!(!IsEmail.filter(&"a b@c.com"));
+        assert!(!IsEmail.filter(&String::from("no-at-sign.com")));
+    }
+}
@@ -0,0 +1,79 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! ExactlyOne implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::filter::Filter;
+
+/// Filter which passes if exactly one of a runtime collection of filters passes
+///
+/// Unlike chaining `xor()`, this does not pass if three or any other odd number of filters pass.
+/// Evaluation stops as soon as a second filter passes.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+pub struct ExactlyOne<N>(Vec<Box<dyn Filter<N>>>);
+
+impl<N> ExactlyOne<N> {
+    pub fn new(filters: Vec<Box<dyn Filter<N>>>) -> ExactlyOne<N> {
+        ExactlyOne(filters)
+    }
+}
+
+impl_boxed_debug!(ExactlyOne, N; filters: 0);
+
+impl<N> Filter<N> for ExactlyOne<N> {
+    fn filter(&self, e: &N) -> bool {
+        self.0.iter().filter(|f| f.filter(e)).take(2).count() == 1
+    }
+}
+
+/// Create a filter which passes if exactly one of the `filters` passes
+pub fn exactly_one<N>(filters: Vec<Box<dyn Filter<N>>>) -> ExactlyOne<N> {
+    ExactlyOne::new(filters)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::checks;
+
+    #[test]
+    fn no_match() {
+        assert!(!exactly_one(checks()).filter(&1));
+        assert!(!exactly_one(checks()).filter(&5));
+    }
+
+    #[test]
+    fn one_match() {
+        assert!(exactly_one(checks()).filter(&2));
+        assert!(exactly_one(checks()).filter(&3));
+        assert!(exactly_one(checks()).filter(&7));
+    }
+
+    #[test]
+    fn two_matches() {
+        assert!(!exactly_one(checks()).filter(&0));
+        assert!(!exactly_one(checks()).filter(&8));
+        assert!(!exactly_one(checks()).filter(&9));
+    }
+
+    #[test]
+    fn three_matches() {
+        assert!(!exactly_one(checks()).filter(&6));
+        assert!(!exactly_one(checks()).filter(&12));
+    }
+
+    #[test]
+    fn empty_fails() {
+        let f: ExactlyOne<usize> = exactly_one(Vec::new());
+        assert!(!f.filter(&0));
+    }
+}
@@ -0,0 +1,341 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Expression filter implementation.
+//!
+//! Compiles a small boolean expression over the input value `x` into a filter at runtime.
+//!
+//! The grammar is intentionally minimal:
+//!
+//! ```text
+//! or      := and ( "||" and )*
+//! and     := unary ( "&&" unary )*
+//! unary   := "!" unary | "(" or ")" | cmp
+//! cmp     := operand ( "<" | "<=" | ">" | ">=" | "==" | "!=" ) operand
+//! operand := "x" | number
+//! ```
+//!
+//! Only available with the `expr` feature.
+//!
+use std::fmt;
+
+use crate::filter::Filter;
+
+/// Error returned if an expression cannot be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    position: usize,
+    message: &'static str,
+}
+
+impl ParseError {
+    fn new(position: usize, message: &'static str) -> ParseError {
+        ParseError { position, message }
+    }
+
+    /// The byte offset in the input at which parsing failed
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operand {
+    X,
+    Num(f64),
+}
+
+impl Operand {
+    fn eval(self, x: f64) -> f64 {
+        match self {
+            Operand::X => x,
+            Operand::Num(n) => n,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CmpOp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Or(Box<Expr>, Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Cmp(Operand, CmpOp, Operand),
+}
+
+impl Expr {
+    fn eval(&self, x: f64) -> bool {
+        match self {
+            Expr::Or(a, b) => a.eval(x) || b.eval(x),
+            Expr::And(a, b) => a.eval(x) && b.eval(x),
+            Expr::Not(a) => !a.eval(x),
+            Expr::Cmp(l, op, r) => {
+                let (l, r) = (l.eval(x), r.eval(x));
+                match op {
+                    CmpOp::Lt => l < r,
+                    CmpOp::Le => l <= r,
+                    CmpOp::Gt => l > r,
+                    CmpOp::Ge => l >= r,
+                    CmpOp::Eq => l == r,
+                    CmpOp::Ne => l != r,
+                }
+            }
+        }
+    }
+}
+
+/// Nesting depth of `!` and parentheses at which parsing fails, so that deeply nested input
+/// cannot overflow the stack
+const MAX_DEPTH: usize = 128;
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let trimmed = self.rest().trim_start();
+        self.pos = self.input.len() - trimmed.len();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, ParseError> {
+        let mut lhs = self.parse_and()?;
+        while self.eat("||") {
+            let rhs = self.parse_and()?;
+            lhs = Expr::Or(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, ParseError> {
+        let mut lhs = self.parse_unary()?;
+        while self.eat("&&") {
+            let rhs = self.parse_unary()?;
+            lhs = Expr::And(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn enter(&mut self, position: usize) -> Result<(), ParseError> {
+        if self.depth == MAX_DEPTH {
+            return Err(ParseError::new(position, "expression nested too deeply"));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, ParseError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        if !self.rest().starts_with("!=") && self.eat("!") {
+            self.enter(start)?;
+            let inner = self.parse_unary()?;
+            self.depth -= 1;
+            return Ok(Expr::Not(Box::new(inner)));
+        }
+
+        if self.eat("(") {
+            self.enter(start)?;
+            let inner = self.parse_or()?;
+            self.depth -= 1;
+            if !self.eat(")") {
+                return Err(ParseError::new(self.pos, "expected ')'"));
+            }
+            return Ok(inner);
+        }
+
+        self.parse_cmp()
+    }
+
+    fn parse_cmp(&mut self) -> Result<Expr, ParseError> {
+        let lhs = self.parse_operand()?;
+        let op = self.parse_cmp_op()?;
+        let rhs = self.parse_operand()?;
+        Ok(Expr::Cmp(lhs, op, rhs))
+    }
+
+    fn parse_cmp_op(&mut self) -> Result<CmpOp, ParseError> {
+        // Two-character operators have to be tried before their one-character prefixes
+        let ops = [
+            ("<=", CmpOp::Le),
+            (">=", CmpOp::Ge),
+            ("==", CmpOp::Eq),
+            ("!=", CmpOp::Ne),
+            ("<", CmpOp::Lt),
+            (">", CmpOp::Gt),
+        ];
+
+        for (token, op) in ops.iter() {
+            if self.eat(token) {
+                return Ok(*op);
+            }
+        }
+
+        Err(ParseError::new(self.pos, "expected comparison operator"))
+    }
+
+    fn parse_operand(&mut self) -> Result<Operand, ParseError> {
+        if self.eat("x") {
+            return Ok(Operand::X);
+        }
+
+        let rest = self.rest();
+        let len = rest
+            .char_indices()
+            .find(|&(i, c)| !(c.is_ascii_digit() || c == '.' || (i == 0 && c == '-')))
+            .map(|(i, _)| i)
+            .unwrap_or(rest.len());
+
+        rest[..len]
+            .parse()
+            .map(|n| {
+                self.pos += len;
+                Operand::Num(n)
+            })
+            .map_err(|_| ParseError::new(self.pos, "expected 'x' or a number"))
+    }
+}
+
+/// Filter which evaluates a boolean expression over `x`, bound to the input
+///
+/// ```
+/// use filters::filter::Filter;
+/// use filters::ops::expr::ExprFilter;
+///
+/// let f = ExprFilter::parse("x > 5 && x < 20").unwrap();
+///
+/// assert!(!f.filter(&5.0));
+/// assert!(f.filter(&10.0));
+/// assert!(!f.filter(&20.0));
+/// ```
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct ExprFilter(Expr);
+
+impl ExprFilter {
+    pub fn parse(s: &str) -> Result<ExprFilter, ParseError> {
+        let mut parser = Parser {
+            input: s,
+            pos: 0,
+            depth: 0,
+        };
+        let expr = parser.parse_or()?;
+
+        parser.skip_whitespace();
+        if parser.pos != s.len() {
+            return Err(ParseError::new(parser.pos, "unexpected trailing input"));
+        }
+
+        Ok(ExprFilter(expr))
+    }
+}
+
+impl Filter<f64> for ExprFilter {
+    fn filter(&self, e: &f64) -> bool {
+        self.0.eval(*e)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn comparisons() {
+        let f = ExprFilter::parse("x >= 3").unwrap();
+        assert!(!f.filter(&2.0));
+        assert!(f.filter(&3.0));
+
+        let f = ExprFilter::parse("x != 3").unwrap();
+        assert!(f.filter(&2.0));
+        assert!(!f.filter(&3.0));
+
+        let f = ExprFilter::parse("-1.5 < x").unwrap();
+        assert!(!f.filter(&-2.0));
+        assert!(f.filter(&0.0));
+    }
+
+    #[test]
+    fn conjunction_and_disjunction() {
+        let f = ExprFilter::parse("x < 0 || x > 5 && x < 20").unwrap();
+        // && binds tighter than ||
+        assert!(f.filter(&-1.0));
+        assert!(!f.filter(&3.0));
+        assert!(f.filter(&10.0));
+        assert!(!f.filter(&25.0));
+    }
+
+    #[test]
+    fn negation_and_parentheses() {
+        let f = ExprFilter::parse("!(x > 5 && x < 20)").unwrap();
+        assert!(f.filter(&5.0));
+        assert!(!f.filter(&10.0));
+        assert!(f.filter(&20.0));
+
+        let f = ExprFilter::parse("!!(x == 1)").unwrap();
+        assert!(f.filter(&1.0));
+        assert!(!f.filter(&2.0));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(ExprFilter::parse("x >").err().unwrap().position(), 3);
+        assert_eq!(ExprFilter::parse("x > 5 &&").err().unwrap().position(), 8);
+        assert_eq!(ExprFilter::parse("(x > 5").err().unwrap().position(), 6);
+        assert_eq!(ExprFilter::parse("x > 5 y").err().unwrap().position(), 6);
+        assert_eq!(ExprFilter::parse("y > 5").err().unwrap().position(), 0);
+    }
+
+    #[test]
+    fn nesting_limit() {
+        let nested = |open: &str, depth: usize| {
+            let close = if open == "(" { ")" } else { "" };
+            format!("{}x > 1{}", open.repeat(depth), close.repeat(depth))
+        };
+
+        assert!(ExprFilter::parse(&nested("(", MAX_DEPTH)).is_ok());
+        assert!(ExprFilter::parse(&nested("!", MAX_DEPTH)).is_ok());
+
+        for open in ["(", "!"] {
+            let err = ExprFilter::parse(&nested(open, 10_000)).err().unwrap();
+            assert_eq!(err.message, "expression nested too deeply");
+            assert_eq!(err.position(), MAX_DEPTH);
+        }
+    }
+}
@@ -13,8 +13,11 @@
 use crate::failable::filter::FailableFilter;
 use crate::filter::Filter;
 
+/// Failable filter which owns a filter and never fails
+///
+/// This is the owned sibling of `AsFailable`, so it can be stored without borrowing the filter.
 #[must_use = "filters are lazy and do nothing unless consumed"]
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct IntoFailable<F>(F);
 
 impl<F> IntoFailable<F> {
@@ -34,12 +37,15 @@ where
     }
 }
 
+/// Failable filter which borrows a filter and never fails
+///
+/// Use `IntoFailable` to take the filter by value instead.
 #[must_use = "filters are lazy and do nothing unless consumed"]
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct AsFailable<'a, F: 'a + ?Sized>(&'a F);
 
 impl<'a, F: 'a + ?Sized> AsFailable<'a, F> {
-    pub fn new(a: &'a F) -> AsFailable<F> {
+    pub fn new(a: &'a F) -> AsFailable<'a, F> {
         AsFailable(a)
     }
 }
@@ -54,3 +60,81 @@ where
         Ok(self.0.filter(e))
     }
 }
+
+/// Failable filter which only runs a failable filter if a filter passes
+///
+/// If the guard does not pass, the decision is `Ok(false)` and the failable filter is not called.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct AndThenFailable<G, F>(G, F);
+
+impl<G, F> AndThenFailable<G, F> {
+    pub fn new(guard: G, f: F) -> AndThenFailable<G, F> {
+        AndThenFailable(guard, f)
+    }
+}
+
+impl<G, F, N> FailableFilter<N> for AndThenFailable<G, F>
+where
+    G: Filter<N>,
+    F: FailableFilter<N>,
+{
+    type Error = F::Error;
+
+    fn filter(&self, e: &N) -> Result<bool, Self::Error> {
+        if self.0.filter(e) {
+            self.1.filter(e)
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn as_failable_borrows() {
+        let f = |&a: &usize| a > 5;
+        let a = AsFailable::new(&f);
+
+        assert_eq!(a.filter(&3), Ok(false));
+        assert_eq!(a.filter(&7), Ok(true));
+        assert!(f.filter(&7));
+    }
+
+    #[test]
+    fn and_then_failable_skips_rejected_inputs() {
+        let check = |&a: &usize| -> Result<bool, &'static str> {
+            if a == 0 {
+                Err("zero")
+            } else {
+                Ok(a % 2 == 0)
+            }
+        };
+        let f = (|&a: &usize| a > 0).and_then_failable(check);
+
+        assert_eq!(f.filter(&0), Ok(false));
+        assert_eq!(f.filter(&3), Ok(false));
+        assert_eq!(f.filter(&4), Ok(true));
+        assert_eq!(check.filter(&0), Err("zero"));
+
+        let f = (|_: &usize| true).and_then_failable(check);
+        assert_eq!(f.filter(&0), Err("zero"));
+    }
+
+    #[test]
+    fn into_failable_can_be_stored() {
+        struct Holder<F> {
+            filter: IntoFailable<F>,
+        }
+
+        let h = Holder {
+            filter: (|&a: &usize| a > 5).into_failable(),
+        };
+
+        assert_eq!(h.filter.filter(&3), Ok(false));
+        assert_eq!(h.filter.filter(&7), Ok(true));
+    }
+}
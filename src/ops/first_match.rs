@@ -0,0 +1,104 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! First match implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use alloc::vec::Vec;
+
+use crate::filter::{BoxedFilter, Filter};
+
+/// A rule of a `FirstMatch`: the guard and the verdict filter
+pub type Rule<N> = (BoxedFilter<N>, BoxedFilter<N>);
+
+/// Filter which returns the verdict of the first rule which applies
+///
+/// Each rule is a pair of a guard and a verdict filter. The rules are tried in order, and the
+/// verdict of the first rule whose guard passes is the decision. If no guard passes, the default
+/// is returned.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+pub struct FirstMatch<N>(Vec<Rule<N>>, bool);
+
+impl<N> FirstMatch<N> {
+    pub fn new(rules: Vec<Rule<N>>, default: bool) -> FirstMatch<N> {
+        FirstMatch(rules, default)
+    }
+}
+
+impl_boxed_debug!(FirstMatch, N; rules: 0, default: 1);
+
+impl<N> Filter<N> for FirstMatch<N> {
+    fn filter(&self, e: &N) -> bool {
+        self.0
+            .iter()
+            .find(|(guard, _)| guard.filter(e))
+            .map(|(_, verdict)| verdict.filter(e))
+            .unwrap_or(self.1)
+    }
+}
+
+/// Create a filter which returns the verdict of the first rule whose guard passes
+///
+/// ```
+/// use filters::filter::Filter;
+/// use filters::ops::first_match::first_match;
+///
+/// let f = first_match(vec![
+///     (Box::new(|&a: &usize| a < 10), Box::new(|&a: &usize| a % 2 == 0)),
+///     (Box::new(|&a: &usize| a < 100), Box::new(|_: &usize| true)),
+/// ], false);
+///
+/// assert!(f.filter(&4));
+/// assert!(!f.filter(&5));
+/// assert!(f.filter(&55));
+/// assert!(!f.filter(&500));
+/// ```
+pub fn first_match<N>(rules: Vec<Rule<N>>, default: bool) -> FirstMatch<N> {
+    FirstMatch::new(rules, default)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::checks;
+
+    fn rules() -> Vec<Rule<usize>> {
+        let verdicts: Vec<BoxedFilter<usize>> = vec![
+            Box::new(|&a: &usize| a % 2 == 0),
+            Box::new(|_: &usize| true),
+            Box::new(|_: &usize| false),
+        ];
+        checks().into_iter().zip(verdicts).collect()
+    }
+
+    #[test]
+    fn precedence() {
+        let f = first_match(rules(), false);
+
+        assert!(f.filter(&8));
+        assert!(!f.filter(&7));
+        assert!(!f.filter(&9));
+        assert!(f.filter(&4));
+        assert!(!f.filter(&3));
+    }
+
+    #[test]
+    fn default_fallthrough() {
+        assert!(!first_match(rules(), false).filter(&5));
+        assert!(first_match(rules(), true).filter(&5));
+        assert!(first_match(Vec::new(), true).filter(&3));
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", first_match(rules(), true)),
+            "FirstMatch { rules: 3, default: true }"
+        );
+    }
+}
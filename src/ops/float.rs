@@ -0,0 +1,80 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Floating point filter implementations.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use crate::filter::Filter;
+
+/// Filter which accepts numbers within a percentage tolerance of a target
+///
+/// The tolerance is relative to the target, so a target of `0.0` only accepts exactly `0.0`.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct WithinPercent(f64, f64);
+
+impl WithinPercent {
+    pub fn new(target: f64, percent: f64) -> WithinPercent {
+        WithinPercent(target, percent)
+    }
+}
+
+impl Filter<f64> for WithinPercent {
+    fn filter(&self, e: &f64) -> bool {
+        if self.0 == 0.0 {
+            return *e == 0.0;
+        }
+
+        (e - self.0).abs() <= self.0.abs() * self.1 / 100.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn inside_and_outside() {
+        let f = WithinPercent::new(100.0, 10.0);
+
+        assert!(f.filter(&100.0));
+        assert!(f.filter(&95.0));
+        assert!(f.filter(&105.5));
+        assert!(!f.filter(&80.0));
+        assert!(!f.filter(&120.0));
+    }
+
+    #[test]
+    fn boundary_is_inclusive() {
+        let f = WithinPercent::new(100.0, 10.0);
+
+        assert!(f.filter(&90.0));
+        assert!(f.filter(&110.0));
+        assert!(!f.filter(&89.999));
+        assert!(!f.filter(&110.001));
+    }
+
+    #[test]
+    fn negative_target() {
+        let f = WithinPercent::new(-50.0, 20.0);
+
+        assert!(f.filter(&-40.0));
+        assert!(f.filter(&-60.0));
+        assert!(!f.filter(&40.0));
+    }
+
+    #[test]
+    fn zero_target() {
+        let f = WithinPercent::new(0.0, 50.0);
+
+        assert!(f.filter(&0.0));
+        assert!(f.filter(&-0.0));
+        assert!(!f.filter(&0.001));
+        assert!(!f.filter(&-0.001));
+    }
+}
@@ -0,0 +1,109 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! FromFn implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::filter::Filter;
+
+/// Filter which calls a closure
+///
+/// Unlike a bare closure, the type names the type of the filtered items, so it can be used as the
+/// type of a struct field or in a return type.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+pub struct FromFn<F, N>(F, PhantomData<fn(&N) -> bool>);
+
+impl<F, N> FromFn<F, N>
+where
+    F: Fn(&N) -> bool,
+{
+    pub fn new(f: F) -> FromFn<F, N> {
+        FromFn(f, PhantomData)
+    }
+}
+
+impl<F: Clone, N> Clone for FromFn<F, N> {
+    fn clone(&self) -> FromFn<F, N> {
+        FromFn(self.0.clone(), PhantomData)
+    }
+}
+
+/// Closures cannot be printed, so only the type name is shown
+impl<F, N> fmt::Debug for FromFn<F, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FromFn").finish()
+    }
+}
+
+impl<F, N> Filter<N> for FromFn<F, N>
+where
+    F: Fn(&N) -> bool,
+{
+    fn filter(&self, e: &N) -> bool {
+        (self.0)(e)
+    }
+}
+
+/// Create a filter from a closure
+///
+/// ```
+/// use filters::filter::Filter;
+/// use filters::ops::from_fn::FromFn;
+///
+/// struct Config {
+///     accept: FromFn<fn(&u64) -> bool, u64>,
+/// }
+///
+/// let c = Config { accept: filters::from_fn(|&x: &u64| x > 5) };
+///
+/// assert!(!c.accept.filter(&3));
+/// assert!(c.accept.filter(&7));
+/// ```
+pub fn from_fn<F, N>(f: F) -> FromFn<F, N>
+where
+    F: Fn(&N) -> bool,
+{
+    FromFn::new(f)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Rules<F: Fn(&u64) -> bool> {
+        accept: FromFn<F, u64>,
+    }
+
+    fn in_range(min: u64, max: u64) -> FromFn<impl Fn(&u64) -> bool + Clone, u64> {
+        from_fn(move |&x: &u64| min <= x && x < max)
+    }
+
+    #[test]
+    fn stored_in_struct() {
+        let rules = Rules {
+            accept: in_range(5, 10),
+        };
+
+        let v: Vec<u64> = (0..20).filter(|x| rules.accept.filter(x)).collect();
+        assert_eq!(v, vec![5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn clone_and_combine() {
+        let a = in_range(5, 10);
+        let b = a.clone().and(|&x: &u64| x % 2 == 0);
+
+        assert!(a.filter(&7));
+        assert!(!b.filter(&7));
+        assert!(b.filter(&8));
+        assert_eq!(format!("{:?}", a), "FromFn");
+    }
+}
@@ -0,0 +1,89 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Geographic bounding box implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use crate::filter::Filter;
+
+/// Filter over `(lat, lon)` coordinates which accepts points inside a bounding box
+///
+/// The bounds are inclusive. If `min_lon > max_lon`, the box crosses the antimeridian and
+/// wraps around from `min_lon` over ±180° to `max_lon`.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InBoundingBox {
+    pub min_lat: f64,
+    pub max_lat: f64,
+    pub min_lon: f64,
+    pub max_lon: f64,
+}
+
+impl InBoundingBox {
+    pub fn new(min_lat: f64, max_lat: f64, min_lon: f64, max_lon: f64) -> InBoundingBox {
+        InBoundingBox {
+            min_lat,
+            max_lat,
+            min_lon,
+            max_lon,
+        }
+    }
+}
+
+impl Filter<(f64, f64)> for InBoundingBox {
+    fn filter(&self, &(lat, lon): &(f64, f64)) -> bool {
+        let lat_ok = self.min_lat <= lat && lat <= self.max_lat;
+        let lon_ok = if self.min_lon <= self.max_lon {
+            self.min_lon <= lon && lon <= self.max_lon
+        } else {
+            self.min_lon <= lon || lon <= self.max_lon
+        };
+
+        lat_ok && lon_ok
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn inside_and_outside() {
+        let europe = InBoundingBox::new(35.0, 70.0, -10.0, 40.0);
+
+        assert!(europe.filter(&(52.5, 13.4)));
+        assert!(!europe.filter(&(40.7, -74.0)));
+        assert!(!europe.filter(&(-33.9, 18.4)));
+    }
+
+    #[test]
+    fn boundary_is_inclusive() {
+        let b = InBoundingBox::new(0.0, 10.0, 0.0, 10.0);
+
+        assert!(b.filter(&(0.0, 0.0)));
+        assert!(b.filter(&(10.0, 10.0)));
+        assert!(b.filter(&(0.0, 5.0)));
+        assert!(!b.filter(&(10.1, 5.0)));
+        assert!(!b.filter(&(5.0, -0.1)));
+    }
+
+    #[test]
+    fn antimeridian_wrap_around() {
+        let pacific = InBoundingBox::new(-30.0, 30.0, 170.0, -170.0);
+
+        assert!(pacific.filter(&(0.0, 175.0)));
+        assert!(pacific.filter(&(0.0, 180.0)));
+        assert!(pacific.filter(&(0.0, -180.0)));
+        assert!(pacific.filter(&(0.0, -175.0)));
+        assert!(pacific.filter(&(0.0, 170.0)));
+        assert!(pacific.filter(&(0.0, -170.0)));
+        assert!(!pacific.filter(&(0.0, 0.0)));
+        assert!(!pacific.filter(&(0.0, 160.0)));
+        assert!(!pacific.filter(&(40.0, 175.0)));
+    }
+}
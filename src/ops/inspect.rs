@@ -0,0 +1,86 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Inspect implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use crate::filter::Filter;
+
+/// Filter which passes every input and the decision for it to a closure
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct Inspect<F, G>(F, G);
+
+impl<F, G> Inspect<F, G> {
+    pub fn new(a: F, g: G) -> Inspect<F, G> {
+        Inspect(a, g)
+    }
+}
+
+impl<N, F, G> Filter<N> for Inspect<F, G>
+where
+    F: Filter<N>,
+    G: Fn(&N, bool),
+{
+    fn filter(&self, e: &N) -> bool {
+        let decision = self.0.filter(e);
+        (self.1)(e, decision);
+        decision
+    }
+}
+
+impl_bit_operators!(Inspect, F, G);
+impl_not_operator!(Inspect, F, G);
+
+#[cfg(test)]
+mod test {
+    use std::cell::{Cell, RefCell};
+
+    use crate::filter::Filter;
+
+    #[test]
+    fn called_once_per_input() {
+        let calls = Cell::new(0);
+        let accepted = Cell::new(0);
+        let f = (|&a: &usize| a > 5).inspect(|_, decision| {
+            calls.set(calls.get() + 1);
+            if decision {
+                accepted.set(accepted.get() + 1);
+            }
+        });
+
+        let r: Vec<usize> = (0..10).filter(|a| f.filter(a)).collect();
+
+        assert_eq!(r, vec![6, 7, 8, 9]);
+        assert_eq!(calls.get(), 10);
+        assert_eq!(accepted.get(), 4);
+    }
+
+    #[test]
+    fn sees_input_and_decision() {
+        let seen = RefCell::new(Vec::new());
+        let f =
+            (|&a: &usize| a % 2 == 0).inspect(|&a, decision| seen.borrow_mut().push((a, decision)));
+
+        assert!(f.filter(&2));
+        assert!(!f.filter(&3));
+        assert_eq!(*seen.borrow(), vec![(2, true), (3, false)]);
+    }
+
+    #[test]
+    fn operators() {
+        let calls = Cell::new(0);
+        let f = (|&a: &usize| a > 5).inspect(|_, _| calls.set(calls.get() + 1));
+        let g = !f & (|&a: &usize| a % 2 == 0);
+
+        let r: Vec<usize> = (0..10).filter(|a| g.filter(a)).collect();
+
+        assert_eq!(r, vec![0, 2, 4]);
+        assert_eq!(calls.get(), 10);
+    }
+}
@@ -9,13 +9,13 @@
 //! Will be automatically included when including `filter::Filter`, so importing this module
 //! shouldn't be necessary.
 //!
-use std::borrow::Borrow;
-use std::marker::PhantomData;
+use core::borrow::Borrow;
+use core::fmt;
+use core::marker::PhantomData;
 
 use crate::filter::Filter;
 
 #[must_use = "filters are lazy and do nothing unless consumed"]
-#[derive(Clone)]
 pub struct MapInput<F, M, FT, B>(F, M, PhantomData<FT>, PhantomData<B>);
 
 impl<F, M, FT, B> MapInput<F, M, FT, B> {
@@ -24,6 +24,23 @@ impl<F, M, FT, B> MapInput<F, M, FT, B> {
     }
 }
 
+/// Only the filter and the closure need to be `Clone`, the marker types do not
+impl<F: Clone, M: Clone, FT, B> Clone for MapInput<F, M, FT, B> {
+    fn clone(&self) -> Self {
+        MapInput(self.0.clone(), self.1.clone(), PhantomData, PhantomData)
+    }
+}
+
+/// Only the filter and the closure need to be `Debug`, the marker types do not
+impl<F: fmt::Debug, M: fmt::Debug, FT, B> fmt::Debug for MapInput<F, M, FT, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("MapInput")
+            .field(&self.0)
+            .field(&self.1)
+            .finish()
+    }
+}
+
 impl<FT, F, T, B, M> Filter<T> for MapInput<F, M, FT, B>
 where
     F: Filter<FT>,
@@ -34,3 +51,138 @@ where
         self.0.filter(self.1(e).borrow())
     }
 }
+
+/// Filter which maps its input with a closure before passing it to the inner filter
+///
+/// Unlike `MapInput`, the closure returns the input of the inner filter by value, so no type
+/// annotations besides the closure argument are necessary.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct MapInput2<F, M>(F, M);
+
+impl<F, M> MapInput2<F, M> {
+    pub fn new(a: F, m: M) -> MapInput2<F, M> {
+        MapInput2(a, m)
+    }
+}
+
+impl<N, T, F, M> Filter<T> for MapInput2<F, M>
+where
+    F: Filter<N>,
+    M: Fn(&T) -> N,
+{
+    fn filter(&self, e: &T) -> bool {
+        self.0.filter(&(self.1)(e))
+    }
+}
+
+/// Filter which passes a field of its input to the inner filter
+///
+/// The closure projects the input to a reference into it, so the field is not copied.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct MapInputField<F, M>(F, M);
+
+impl<F, M> MapInputField<F, M> {
+    pub fn new(a: F, m: M) -> MapInputField<F, M> {
+        MapInputField(a, m)
+    }
+}
+
+impl<N, T, F, M> Filter<T> for MapInputField<F, M>
+where
+    F: Filter<N>,
+    M: Fn(&T) -> &N,
+{
+    fn filter(&self, e: &T) -> bool {
+        self.0.filter((self.1)(e))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::Filter;
+
+    #[cfg(feature = "alloc")]
+    struct Person {
+        name: String,
+        age: u8,
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn map_input_field() {
+        use crate::ops::cmp::gt;
+        use crate::ops::string::starts_with;
+
+        let people = [
+            Person {
+                name: String::from("Alice"),
+                age: 17,
+            },
+            Person {
+                name: String::from("Anna"),
+                age: 32,
+            },
+            Person {
+                name: String::from("Bob"),
+                age: 40,
+            },
+        ];
+
+        let f = gt(18)
+            .map_input_field(|p: &Person| &p.age)
+            .and(starts_with("A").map_input_field(|p: &Person| &p.name));
+
+        let names: Vec<&str> = people
+            .iter()
+            .filter(|p| f.filter(p))
+            .map(|p| p.name.as_str())
+            .collect();
+
+        assert_eq!(names, vec!["Anna"]);
+    }
+
+    #[test]
+    fn map_input_clone_without_clone_marker() {
+        use crate::ops::map::MapInput;
+
+        struct NotClone(u8);
+
+        let a: MapInput<_, _, NotClone, NotClone> =
+            MapInput::new(|n: &NotClone| n.0 > 3, |&x: &u8| NotClone(x));
+        let b = a.clone();
+
+        assert!(b.filter(&4));
+        assert!(!b.filter(&2));
+    }
+
+    #[test]
+    fn map_input_debug_without_debug_marker() {
+        use crate::ops::bool::Bool;
+        use crate::ops::map::MapInput;
+
+        struct NotDebug;
+
+        let a: MapInput<Bool, Bool, NotDebug, NotDebug> =
+            MapInput::new(Bool::new(true), Bool::new(false));
+
+        assert_eq!(format!("{:?}", a), "MapInput(Bool(true), Bool(false))");
+    }
+
+    #[test]
+    fn map_input_with() {
+        let a = (|&a: &i64| a < 7).map_input_with(|&x: &usize| x as i64);
+
+        assert!(a.filter(&3));
+        assert!(!a.filter(&9));
+    }
+
+    #[test]
+    fn map_input_with_owned_value() {
+        let a = (|s: &String| s.len() > 3).map_input_with(|&x: &usize| x.to_string());
+
+        assert!(!a.filter(&123));
+        assert!(a.filter(&1234));
+    }
+}
@@ -0,0 +1,81 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Match count implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use alloc::vec::Vec;
+
+use crate::filter::BoxedFilter;
+
+/// Evaluator which counts how many of its sub-filters an element passes
+///
+/// This is not a filter itself, but can be used to score elements before thresholding them.
+/// All sub-filters are evaluated.
+pub struct MatchCount<N>(Vec<BoxedFilter<N>>);
+
+impl<N> MatchCount<N> {
+    pub fn new(filters: Vec<BoxedFilter<N>>) -> MatchCount<N> {
+        MatchCount(filters)
+    }
+
+    /// The number of sub-filters which pass for `item`
+    pub fn count(&self, item: &N) -> usize {
+        self.0.iter().filter(|f| f.filter(item)).count()
+    }
+}
+
+impl_boxed_debug!(MatchCount, N; filters: 0);
+
+/// Create an evaluator which counts how many of the `filters` an element passes
+///
+/// ```
+/// use filters::ops::match_count::count_matches_of;
+///
+/// let m = count_matches_of(vec![
+///     Box::new(|&a: &usize| a > 5),
+///     Box::new(|&a: &usize| a % 2 == 0),
+///     Box::new(|&a: &usize| a > 100),
+/// ]);
+///
+/// assert_eq!(m.count(&3), 0);
+/// assert_eq!(m.count(&7), 1);
+/// assert_eq!(m.count(&8), 2);
+/// assert_eq!(m.count(&108), 3);
+/// ```
+pub fn count_matches_of<N>(filters: Vec<BoxedFilter<N>>) -> MatchCount<N> {
+    MatchCount::new(filters)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::filter::Filter;
+    use crate::ops::range::in_range;
+
+    #[test]
+    fn overlapping_ranges() {
+        let m = count_matches_of(vec![
+            in_range(0..10).boxed(),
+            in_range(5..15).boxed(),
+            in_range(8..20).boxed(),
+        ]);
+
+        assert_eq!(m.count(&2), 1);
+        assert_eq!(m.count(&6), 2);
+        assert_eq!(m.count(&9), 3);
+        assert_eq!(m.count(&12), 2);
+        assert_eq!(m.count(&17), 1);
+        assert_eq!(m.count(&25), 0);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(count_matches_of::<usize>(Vec::new()).count(&1), 0);
+    }
+}
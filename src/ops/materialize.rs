@@ -0,0 +1,76 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Materialized filter decisions.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use alloc::vec::Vec;
+
+/// The precomputed decisions of a filter for a fixed dataset
+///
+/// Created by `Filter::materialize()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MaterializedFilter(Vec<bool>);
+
+impl MaterializedFilter {
+    pub fn new(decisions: Vec<bool>) -> MaterializedFilter {
+        MaterializedFilter(decisions)
+    }
+
+    /// Whether the item at `index` passed the filter
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds of the dataset the filter was materialized for.
+    pub fn passes(&self, index: usize) -> bool {
+        self.0[index]
+    }
+
+    /// The number of items the filter was materialized for
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::Filter;
+
+    #[test]
+    fn materialized_matches_filter() {
+        let items = vec![1, 7, 3, 12, 5, 9];
+        let f = |&a: &usize| a > 4;
+        let m = f.materialize(&items);
+
+        assert_eq!(m.len(), items.len());
+        for (i, item) in items.iter().enumerate() {
+            assert_eq!(m.passes(i), f.filter(item));
+        }
+
+        assert!(!m.passes(0));
+        assert!(m.passes(1));
+        assert!(m.passes(3));
+    }
+
+    #[test]
+    fn materialize_empty() {
+        let m = (|&a: &usize| a > 4).materialize(&[]);
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        let m = (|&a: &usize| a > 4).materialize(&[1, 2]);
+        let _ = m.passes(2);
+    }
+}
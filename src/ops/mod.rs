@@ -4,10 +4,79 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+#[cfg(feature = "alloc")]
+pub mod all;
 pub mod and;
+#[cfg(feature = "alloc")]
+pub mod and_all;
+#[cfg(feature = "alloc")]
+pub mod any;
 pub mod bool;
+pub mod by_ref;
+#[cfg(feature = "std")]
+pub mod cache;
+#[cfg(feature = "std")]
+pub mod catch_panic;
+pub mod cmp;
+pub mod counting;
+#[cfg(feature = "std")]
+pub mod debounce_time;
+pub mod dyn_range;
+#[cfg(feature = "email")]
+pub mod email;
+#[cfg(feature = "alloc")]
+pub mod exactly_one;
+#[cfg(feature = "expr")]
+pub mod expr;
 pub mod failable;
+#[cfg(feature = "alloc")]
+pub mod first_match;
+#[cfg(feature = "std")]
+pub mod float;
+pub mod from_fn;
+pub mod geo;
+pub mod inspect;
 pub mod map;
+#[cfg(feature = "alloc")]
+pub mod match_count;
+#[cfg(feature = "alloc")]
+pub mod materialize;
+pub mod monotonic;
 pub mod not;
+pub mod nth;
+pub mod on_ref;
 pub mod or;
+#[cfg(feature = "alloc")]
+pub mod or_all;
+pub mod range;
+#[cfg(feature = "std")]
+pub mod rate_limit;
+#[cfg(feature = "regex")]
+pub mod regex;
+pub mod select;
+#[cfg(feature = "alloc")]
+pub mod set;
+#[cfg(feature = "std")]
+pub mod shard;
+pub mod spy;
+#[cfg(feature = "alloc")]
+pub mod string;
+#[cfg(feature = "alloc")]
+pub mod threshold;
+pub mod toggle;
+pub mod traced;
+#[cfg(feature = "std")]
+pub mod transition;
+#[cfg(feature = "uuid")]
+pub mod uuid;
+#[cfg(feature = "alloc")]
+pub mod weighted;
+pub mod xnor;
 pub mod xor;
+
+#[cfg(feature = "alloc")]
+pub use self::all::all;
+#[cfg(feature = "alloc")]
+pub use self::any::any;
+#[cfg(feature = "alloc")]
+pub use self::exactly_one::exactly_one;
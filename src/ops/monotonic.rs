@@ -0,0 +1,70 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Ordering filter implementations.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use crate::filter::Filter;
+
+/// Filter over `(prev, next)` pairs which accepts pairs with `prev <= next`
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct NonDecreasing;
+
+impl<T: PartialOrd> Filter<(T, T)> for NonDecreasing {
+    fn filter(&self, (prev, next): &(T, T)) -> bool {
+        prev <= next
+    }
+}
+
+/// Filter over `(prev, next)` pairs which accepts pairs with `prev >= next`
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct NonIncreasing;
+
+impl<T: PartialOrd> Filter<(T, T)> for NonIncreasing {
+    fn filter(&self, (prev, next): &(T, T)) -> bool {
+        prev >= next
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn non_decreasing() {
+        assert!(NonDecreasing.filter(&(1, 2)));
+        assert!(NonDecreasing.filter(&(2, 2)));
+        assert!(!NonDecreasing.filter(&(3, 2)));
+        assert!(!NonDecreasing.filter(&(1.0, f64::NAN)));
+    }
+
+    #[test]
+    fn non_increasing() {
+        assert!(!NonIncreasing.filter(&(1, 2)));
+        assert!(NonIncreasing.filter(&(2, 2)));
+        assert!(NonIncreasing.filter(&(3, 2)));
+        assert!(!NonIncreasing.filter(&(1.0, f64::NAN)));
+    }
+
+    #[test]
+    fn validate_sorted() {
+        let sorted = [1, 2, 2, 5, 8];
+        let unsorted = [1, 3, 2, 5];
+
+        let is_sorted = |s: &[i32]| {
+            s.windows(2)
+                .map(|w| (w[0], w[1]))
+                .all(|p| NonDecreasing.filter(&p))
+        };
+
+        assert!(is_sorted(&sorted));
+        assert!(!is_sorted(&unsorted));
+    }
+}
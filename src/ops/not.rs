@@ -9,16 +9,113 @@
 //! Will be automatically included when including `filter::Filter`, so importing this module
 //! shouldn't be necessary.
 //!
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
 use crate::filter::Filter;
+#[cfg(feature = "alloc")]
+use crate::sql::SqlRenderable;
 
 #[must_use = "filters are lazy and do nothing unless consumed"]
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Not<T>(T);
 
 impl<T> Not<T> {
-    pub fn new(a: T) -> Not<T> {
+    pub const fn new(a: T) -> Not<T> {
         Not(a)
     }
 }
 
 impl_operators!(Not, self e { !self.0.filter(e) }, T);
+impl_bit_operators!(Not, T);
+
+/// Negating a `Not` unwraps the inner filter, so `!!f` has the type of `f`
+impl<T> core::ops::Not for Not<T> {
+    type Output = T;
+
+    fn not(self) -> T {
+        self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: SqlRenderable> SqlRenderable for Not<T> {
+    fn to_sql(&self, column: &str) -> String {
+        format!("(NOT {})", self.0.to_sql(column))
+    }
+}
+
+/// Negation of a borrowed filter
+///
+/// Created by `Filter::as_not()`.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct AsNot<'a, F: 'a + ?Sized>(&'a F);
+
+impl<'a, F: 'a + ?Sized> AsNot<'a, F> {
+    pub fn new(a: &'a F) -> AsNot<'a, F> {
+        AsNot(a)
+    }
+}
+
+impl<'a, F, N> Filter<N> for AsNot<'a, F>
+where
+    F: Filter<N> + 'a + ?Sized,
+{
+    fn filter(&self, e: &N) -> bool {
+        !self.0.filter(e)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::Filter;
+    use crate::ops::and::And;
+    use crate::ops::bool::Bool;
+    use crate::ops::cmp::GreaterThan;
+
+    #[test]
+    fn as_not_borrows() {
+        use super::AsNot;
+        use crate::test_util::EqTo;
+
+        let eq = EqTo { i: 3 };
+        let not_eq = AsNot::new(&eq);
+
+        assert!(!not_eq.filter(&3));
+        assert!(not_eq.filter(&4));
+        assert!(eq.as_not().and(|&a: &usize| a > 3).filter(&5));
+        assert!(eq.filter(&3));
+    }
+
+    #[test]
+    fn not_operator() {
+        let f = || GreaterThan(5);
+        let not_f = !f();
+
+        for i in 0..10 {
+            assert_eq!(not_f.filter(&i), !f().filter(&i));
+        }
+    }
+
+    #[test]
+    fn not_operator_on_composed() {
+        let f = || And::new(|&a: &usize| a > 2, |&a: &usize| a < 7);
+        let not_f = !f();
+
+        for i in 0..10 {
+            assert_eq!(not_f.filter(&i), !f().filter(&i));
+        }
+    }
+
+    #[test]
+    fn double_negation_collapses() {
+        let f: Bool = !!Bool::new(true);
+        assert!(f.filter(&0));
+
+        let f: GreaterThan<usize> = !GreaterThan(5).not();
+        assert!(f.filter(&6));
+    }
+}
@@ -0,0 +1,128 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Nth match implementations.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use core::cell::Cell;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::filter::Filter;
+
+/// Filter which only accepts the `n`th (1-based) item the inner filter accepts
+///
+/// The filter counts the matches it has seen, so it is meant to be used for a single pass over
+/// the items. With `n == 0`, nothing is accepted.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct NthMatch<F>(F, usize, Cell<usize>);
+
+impl<F> NthMatch<F> {
+    pub fn new(a: F, n: usize) -> NthMatch<F> {
+        NthMatch(a, n, Cell::new(0))
+    }
+}
+
+impl<N, F> Filter<N> for NthMatch<F>
+where
+    F: Filter<N>,
+{
+    fn filter(&self, e: &N) -> bool {
+        if !self.0.filter(e) {
+            return false;
+        }
+
+        let seen = self.2.get().saturating_add(1);
+        self.2.set(seen);
+        seen == self.1
+    }
+}
+
+/// Thread safe variant of `NthMatch`
+///
+/// If the filter is shared between threads, exactly one of the concurrently filtered items is
+/// accepted, but which one depends on the order in which the threads reach the counter.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Debug)]
+pub struct AtomicNthMatch<F>(F, usize, AtomicUsize);
+
+impl<F> AtomicNthMatch<F> {
+    pub fn new(a: F, n: usize) -> AtomicNthMatch<F> {
+        AtomicNthMatch(a, n, AtomicUsize::new(0))
+    }
+}
+
+impl<N, F> Filter<N> for AtomicNthMatch<F>
+where
+    F: Filter<N>,
+{
+    fn filter(&self, e: &N) -> bool {
+        if !self.0.filter(e) {
+            return false;
+        }
+
+        let previous = self
+            .2
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |s| {
+                Some(s.saturating_add(1))
+            })
+            .unwrap_or_else(|s| s);
+        previous.saturating_add(1) == self.1
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn accepts_only_nth_match() {
+        let f = (|&a: &usize| a % 2 == 0).nth_match(2);
+        let r: Vec<usize> = (1..20).filter(|a| f.filter(a)).collect();
+
+        assert_eq!(r, vec![4]);
+    }
+
+    #[test]
+    fn zero_accepts_nothing() {
+        let f = (|&a: &usize| a % 2 == 0).nth_match(0);
+        assert_eq!((1..20).filter(|a| f.filter(a)).count(), 0);
+    }
+
+    #[test]
+    fn fewer_matches_than_n() {
+        let f = (|&a: &usize| a % 2 == 0).nth_match(5);
+        assert_eq!((1..8).filter(|a| f.filter(a)).count(), 0);
+    }
+
+    #[test]
+    fn atomic_accepts_only_nth_match() {
+        let f = AtomicNthMatch::new(|&a: &usize| a % 2 == 0, 2);
+        let r: Vec<usize> = (1..20).filter(|a| f.filter(a)).collect();
+
+        assert_eq!(r, vec![4]);
+    }
+
+    #[test]
+    fn atomic_accepts_exactly_one_across_threads() {
+        let f = Arc::new(AtomicNthMatch::new(|&a: &usize| a % 2 == 0, 10));
+
+        let handles: Vec<_> = (0..4)
+            .map(|t| {
+                let f = f.clone();
+                thread::spawn(move || (t * 100..(t + 1) * 100).filter(|a| f.filter(a)).count())
+            })
+            .collect();
+
+        let accepted: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+        assert_eq!(accepted, 1);
+    }
+}
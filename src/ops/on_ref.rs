@@ -0,0 +1,78 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Reference adapter implementations.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use crate::filter::Filter;
+
+/// Filter which filters references with a filter for the referenced values
+///
+/// A `Filter<N>` wrapped into `OnRef` is a `Filter<&'a N>` for every lifetime `'a`, because it
+/// only borrows the referenced value for the duration of the call.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct OnRef<F>(F);
+
+impl<F> OnRef<F> {
+    pub fn new(a: F) -> OnRef<F> {
+        OnRef(a)
+    }
+}
+
+impl<'a, N, F: Filter<N>> Filter<&'a N> for OnRef<F> {
+    fn filter(&self, e: &&'a N) -> bool {
+        self.0.filter(*e)
+    }
+}
+
+/// Filter which filters values with a filter for references to the values
+///
+/// The inner filter has to accept references of every lifetime, because the reference passed to
+/// it only lives for the duration of the call.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct OnOwned<F>(F);
+
+impl<F> OnOwned<F> {
+    pub fn new(a: F) -> OnOwned<F> {
+        OnOwned(a)
+    }
+}
+
+impl<N, F> Filter<N> for OnOwned<F>
+where
+    F: for<'a> Filter<&'a N>,
+{
+    fn filter(&self, e: &N) -> bool {
+        self.0.filter(&e)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::Filter;
+    use crate::iter::FilterWith;
+    use crate::ops::cmp::gt;
+
+    #[test]
+    fn on_ref() {
+        let v: Vec<u64> = vec![1, 7, 3, 9];
+        let r: Vec<&u64> = v.iter().filter_with(gt(5).on_ref()).collect();
+
+        assert_eq!(r, vec![&7, &9]);
+    }
+
+    #[test]
+    fn on_owned() {
+        let f = (|&&x: &&u64| x > 5).on_owned();
+        let r: Vec<u64> = vec![1, 7, 3, 9].into_iter().filter_with(f).collect();
+
+        assert_eq!(r, vec![7, 9]);
+    }
+}
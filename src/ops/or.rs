@@ -9,16 +9,36 @@
 //! Will be automatically included when including `filter::Filter`, so importing this module
 //! shouldn't be necessary.
 //!
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
 use crate::filter::Filter;
+#[cfg(feature = "alloc")]
+use crate::sql::SqlRenderable;
 
+/// Filter which passes if any of both filters passes
+///
+/// The filters are evaluated from left to right, and the right filter is only evaluated if the
+/// left filter does not pass.
 #[must_use = "filters are lazy and do nothing unless consumed"]
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Or<T, U>(T, U);
 
 impl<T, U> Or<T, U> {
-    pub fn new(a: T, b: U) -> Or<T, U> {
+    pub const fn new(a: T, b: U) -> Or<T, U> {
         Or(a, b)
     }
 }
 
 impl_operators!(Or, self e { self.0.filter(e) || self.1.filter(e) }, T, U);
+impl_bit_operators!(Or, T, U);
+impl_not_operator!(Or, T, U);
+
+#[cfg(feature = "alloc")]
+impl<T: SqlRenderable, U: SqlRenderable> SqlRenderable for Or<T, U> {
+    fn to_sql(&self, column: &str) -> String {
+        format!("({} OR {})", self.0.to_sql(column), self.1.to_sql(column))
+    }
+}
@@ -0,0 +1,60 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! OR over a list of filters implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use alloc::vec::Vec;
+
+use crate::filter::Filter;
+
+/// Filter which passes if any of a list of same-typed filters passes
+///
+/// Evaluation stops at the first filter which passes. An empty `OrAll` never passes.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct OrAll<F>(Vec<F>);
+
+impl<F> OrAll<F> {
+    pub fn new(filters: Vec<F>) -> OrAll<F> {
+        OrAll(filters)
+    }
+}
+
+impl_operators!(OrAll, self e { self.0.iter().any(|f| f.filter(e)) }, F);
+impl_bit_operators!(OrAll, F);
+impl_not_operator!(OrAll, F);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::EqTo;
+
+    #[test]
+    fn or_all() {
+        let f = EqTo { i: 1 }.or_all(vec![EqTo { i: 3 }, EqTo { i: 5 }]);
+        let v: Vec<usize> = (0..10).filter(|a| f.filter(a)).collect();
+        assert_eq!(v, vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn empty_rejects() {
+        let f: OrAll<EqTo> = OrAll::new(Vec::new());
+        assert!(!f.filter(&0));
+    }
+
+    #[test]
+    fn short_circuits() {
+        let a: fn(&usize) -> bool = |&a| a > 5;
+        let b: fn(&usize) -> bool = |&a| a > 10;
+        let c: fn(&usize) -> bool = |_| panic!("must not be evaluated");
+
+        let f = a.or_all(vec![b, c]);
+        assert!(f.filter(&7));
+    }
+}
@@ -0,0 +1,129 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Range filter implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use core::ops::RangeBounds;
+
+use crate::filter::Filter;
+
+/// Filter which accepts values contained in a range
+///
+/// Works with every kind of range, so `5..15`, `5..=15`, `5..`, `..15`, `..=15` and `..` can be
+/// used.
+///
+/// ```
+/// use filters::filter::Filter;
+/// use filters::ops::range::in_range;
+///
+/// let f = in_range(5..=15);
+///
+/// assert!(!f.filter(&4));
+/// assert!(f.filter(&5));
+/// assert!(f.filter(&15));
+/// assert!(!f.filter(&16));
+/// ```
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct InRange<R>(R);
+
+impl<R> InRange<R> {
+    pub fn new(range: R) -> InRange<R> {
+        InRange(range)
+    }
+}
+
+impl_bit_operators!(InRange, R);
+impl_not_operator!(InRange, R);
+
+impl<T, R> Filter<T> for InRange<R>
+where
+    T: PartialOrd,
+    R: RangeBounds<T>,
+{
+    fn filter(&self, e: &T) -> bool {
+        self.0.contains(e)
+    }
+}
+
+/// Create a filter which accepts values contained in `range`
+pub fn in_range<T, R>(range: R) -> InRange<R>
+where
+    T: PartialOrd,
+    R: RangeBounds<T>,
+{
+    InRange::new(range)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn half_open() {
+        let f = in_range(5..15);
+        assert!(!f.filter(&4));
+        assert!(f.filter(&5));
+        assert!(f.filter(&14));
+        assert!(!f.filter(&15));
+    }
+
+    #[test]
+    fn inclusive() {
+        let f = in_range(5..=15);
+        assert!(!f.filter(&4));
+        assert!(f.filter(&5));
+        assert!(f.filter(&15));
+        assert!(!f.filter(&16));
+    }
+
+    #[test]
+    fn lower_bound_only() {
+        let f = in_range(5..);
+        assert!(!f.filter(&4));
+        assert!(f.filter(&5));
+        assert!(f.filter(&usize::MAX));
+    }
+
+    #[test]
+    fn upper_bound_only() {
+        let f = in_range(..15);
+        assert!(f.filter(&0));
+        assert!(f.filter(&14));
+        assert!(!f.filter(&15));
+
+        let f = in_range(..=15);
+        assert!(f.filter(&15));
+        assert!(!f.filter(&16));
+    }
+
+    #[test]
+    fn unbounded() {
+        let f = InRange::new(..);
+        assert!(Filter::<usize>::filter(&f, &0));
+        assert!(Filter::<usize>::filter(&f, &usize::MAX));
+    }
+
+    #[test]
+    fn empty_range() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let f = in_range(15..5);
+        assert!(!f.filter(&5));
+        assert!(!f.filter(&10));
+        assert!(!f.filter(&15));
+    }
+
+    #[test]
+    fn floats() {
+        let f = in_range(0.5..=1.5);
+        assert!(!f.filter(&0.4));
+        assert!(f.filter(&1.0));
+        assert!(f.filter(&1.5));
+    }
+}
@@ -0,0 +1,98 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Rate limiting implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use crate::filter::Filter;
+
+/// Filter which accepts at most one item per `Duration`
+///
+/// The first item is always accepted. Every following item is only accepted if at least the
+/// configured duration has passed since the last accepted item, as reported by the clock `C`.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct RateLimit<C>(Duration, Cell<Option<Instant>>, C);
+
+impl<C> RateLimit<C>
+where
+    C: Fn() -> Instant,
+{
+    /// Create a rate limit which reads the current time from `clock`
+    pub fn new(every: Duration, clock: C) -> RateLimit<C> {
+        RateLimit(every, Cell::new(None), clock)
+    }
+}
+
+impl RateLimit<fn() -> Instant> {
+    /// Create a rate limit which reads the current time from `Instant::now()`
+    pub fn with_system_clock(every: Duration) -> RateLimit<fn() -> Instant> {
+        RateLimit::new(every, Instant::now)
+    }
+}
+
+impl<N, C> Filter<N> for RateLimit<C>
+where
+    C: Fn() -> Instant,
+{
+    fn filter(&self, _: &N) -> bool {
+        let now = (self.2)();
+        let accept = self
+            .1
+            .get()
+            .map(|last| now.saturating_duration_since(last) >= self.0)
+            .unwrap_or(true);
+
+        if accept {
+            self.1.set(Some(now));
+        }
+
+        accept
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn accepts_one_item_per_duration() {
+        let start = Instant::now();
+        let ticks = Cell::new(0);
+        let clock = || {
+            let t = ticks.get();
+            ticks.set(t + 1);
+            start + Duration::from_secs(t)
+        };
+
+        // one item per second, so the clock reads 0s, 1s, 2s, ...
+        let limit = RateLimit::new(Duration::from_secs(3), clock);
+
+        let accepted: Vec<usize> = (0..10).filter(|i| limit.filter(i)).collect();
+
+        assert_eq!(accepted, vec![0, 3, 6, 9]);
+    }
+
+    #[test]
+    fn rejected_items_do_not_reset_the_window() {
+        let start = Instant::now();
+        let now = Cell::new(start);
+        let limit = RateLimit::new(Duration::from_secs(2), || now.get());
+
+        assert!(limit.filter(&()));
+        now.set(start + Duration::from_secs(1));
+        assert!(!limit.filter(&()));
+        now.set(start + Duration::from_secs(2));
+        assert!(limit.filter(&()));
+        now.set(start + Duration::from_secs(3));
+        assert!(!limit.filter(&()));
+    }
+}
@@ -0,0 +1,101 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Regular expression filter implementation.
+//!
+//! Only available with the `regex` feature.
+//!
+use crate::filter::Filter;
+
+/// Filter which accepts strings matching a regular expression
+///
+/// Composes with `and()`, `or()`, `not()` and friends like any other filter. As `Matches` filters
+/// both `&str` and `String`, the input type sometimes cannot be inferred from these helpers, the
+/// `&`, `|`, `^` and `!` operators can be used instead.
+///
+/// ```
+/// use filters::filter::Filter;
+/// use filters::ops::regex::Matches;
+///
+/// let f = !Matches::new(r"^\d+$").unwrap();
+///
+/// assert!(f.filter(&"abc"));
+/// assert!(!f.filter(&"123"));
+/// ```
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct Matches(::regex::Regex);
+
+impl Matches {
+    /// Compile `pattern` into a filter
+    pub fn new(pattern: &str) -> Result<Matches, ::regex::Error> {
+        ::regex::Regex::new(pattern).map(Matches)
+    }
+}
+
+impl_bit_operators!(Matches,);
+impl_not_operator!(Matches,);
+
+impl From<::regex::Regex> for Matches {
+    fn from(re: ::regex::Regex) -> Matches {
+        Matches(re)
+    }
+}
+
+impl<'a> Filter<&'a str> for Matches {
+    fn filter(&self, e: &&'a str) -> bool {
+        self.0.is_match(e)
+    }
+}
+
+impl Filter<String> for Matches {
+    fn filter(&self, e: &String) -> bool {
+        self.0.is_match(e)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::iter::FilterWith;
+
+    #[test]
+    fn matches() {
+        let v: Vec<&str> = vec!["foo.rs", "bar.toml", "baz.rs", "rs"]
+            .into_iter()
+            .filter_with(Matches::new(r"\.rs$").unwrap())
+            .collect();
+
+        assert_eq!(v, vec!["foo.rs", "baz.rs"]);
+    }
+
+    #[test]
+    fn matches_strings() {
+        let f = Matches::new("^[a-z]+$").unwrap();
+
+        assert!(f.filter(&String::from("abc")));
+        assert!(!f.filter(&String::from("aBc")));
+    }
+
+    #[test]
+    fn composition() {
+        let f = Matches::new("^a").unwrap() & !Matches::new("z$").unwrap();
+
+        assert!(f.filter(&"abc"));
+        assert!(!f.filter(&"abz"));
+        assert!(!f.filter(&"bcd"));
+
+        let f = Filter::<&str>::or(Matches::new("^a").unwrap(), Matches::new("^b").unwrap());
+        assert!(f.filter(&"abc"));
+        assert!(f.filter(&"bcd"));
+        assert!(!f.filter(&"cde"));
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        assert!(Matches::new("(unclosed").is_err());
+    }
+}
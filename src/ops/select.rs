@@ -0,0 +1,65 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Select implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use crate::filter::Filter;
+
+/// Filter which applies one of two filters, depending on the decision of a condition filter
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct Select<C, A, B>(C, A, B);
+
+impl<C, A, B> Select<C, A, B> {
+    pub fn new(condition: C, if_true: A, if_false: B) -> Select<C, A, B> {
+        Select(condition, if_true, if_false)
+    }
+}
+
+impl_operators!(Select, self e {
+    if self.0.filter(e) {
+        self.1.filter(e)
+    } else {
+        self.2.filter(e)
+    }
+}, C, A, B);
+impl_bit_operators!(Select, C, A, B);
+impl_not_operator!(Select, C, A, B);
+
+#[cfg(test)]
+mod test {
+    use crate::filter::Filter;
+
+    #[test]
+    fn routes_by_condition() {
+        let even = |&a: &usize| a % 2 == 0;
+        let f = even.select(|&a: &usize| a < 10, |&a: &usize| a > 20);
+
+        let r: Vec<usize> = (0..30).filter(|a| f.filter(a)).collect();
+
+        assert_eq!(r, vec![0, 2, 4, 6, 8, 21, 23, 25, 27, 29]);
+    }
+
+    #[test]
+    fn branches_are_only_called_when_selected() {
+        let f = (|&a: &usize| a > 5).select(
+            |&a: &usize| {
+                assert!(a > 5);
+                true
+            },
+            |&a: &usize| {
+                assert!(a <= 5);
+                false
+            },
+        );
+
+        assert!(!f.filter(&3));
+        assert!(f.filter(&7));
+    }
+}
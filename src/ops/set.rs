@@ -0,0 +1,169 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Set membership filter implementations.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+#[cfg(feature = "std")]
+use std::hash::Hash;
+
+use crate::filter::Filter;
+
+#[cfg(feature = "std")]
+/// Filter which accepts values contained in a set
+///
+/// ```
+/// use filters::filter::Filter;
+/// use filters::ops::set::OneOf;
+///
+/// let f = OneOf::from(vec![1, 3, 5]);
+///
+/// assert!(f.filter(&3));
+/// assert!(!f.filter(&4));
+/// ```
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct OneOf<T>(HashSet<T>);
+
+#[cfg(feature = "std")]
+impl<T: Eq + Hash> OneOf<T> {
+    pub fn new<I>(items: I) -> OneOf<T>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        OneOf(items.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "std")]
+impl_bit_operators!(OneOf, T);
+#[cfg(feature = "std")]
+impl_not_operator!(OneOf, T);
+
+#[cfg(feature = "std")]
+impl<T: Eq + Hash> From<Vec<T>> for OneOf<T> {
+    fn from(v: Vec<T>) -> OneOf<T> {
+        OneOf::new(v)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Eq + Hash> From<HashSet<T>> for OneOf<T> {
+    fn from(set: HashSet<T>) -> OneOf<T> {
+        OneOf(set)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Eq + Hash> FromIterator<T> for OneOf<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> OneOf<T> {
+        OneOf::new(iter)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Eq + Hash> Filter<T> for OneOf<T> {
+    fn filter(&self, e: &T) -> bool {
+        self.0.contains(e)
+    }
+}
+
+/// Filter which accepts values contained in a list
+///
+/// Unlike `OneOf`, this does a linear search without hashing, which is usually faster for small
+/// lists.
+///
+/// ```
+/// use filters::filter::Filter;
+/// use filters::ops::set::AnyOf;
+///
+/// let f = AnyOf::from(vec!["a", "b"]);
+///
+/// assert!(f.filter(&"a"));
+/// assert!(!f.filter(&"c"));
+/// ```
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct AnyOf<T>(Vec<T>);
+
+impl<T: PartialEq> AnyOf<T> {
+    pub fn new<I>(items: I) -> AnyOf<T>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        AnyOf(items.into_iter().collect())
+    }
+}
+
+impl_bit_operators!(AnyOf, T);
+impl_not_operator!(AnyOf, T);
+
+impl<T: PartialEq> From<Vec<T>> for AnyOf<T> {
+    fn from(v: Vec<T>) -> AnyOf<T> {
+        AnyOf(v)
+    }
+}
+
+impl<T: PartialEq> FromIterator<T> for AnyOf<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> AnyOf<T> {
+        AnyOf::new(iter)
+    }
+}
+
+impl<T: PartialEq> Filter<T> for AnyOf<T> {
+    fn filter(&self, e: &T) -> bool {
+        self.0.contains(e)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::iter::FilterWith;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn one_of() {
+        let v: Vec<usize> = (0..10).filter_with(OneOf::from(vec![1, 3, 5])).collect();
+        assert_eq!(v, vec![1, 3, 5]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn one_of_collected() {
+        let f: OneOf<usize> = (0..10).filter(|u| u % 4 == 1).collect();
+        let v: Vec<usize> = (0..10).filter_with(f).collect();
+        assert_eq!(v, vec![1, 5, 9]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn one_of_deny_list() {
+        let v: Vec<usize> = (0..6)
+            .filter_with(OneOf::new(vec![1, 3, 5]).not())
+            .collect();
+        assert_eq!(v, vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn any_of() {
+        let v: Vec<usize> = (0..10).filter_with(AnyOf::from(vec![1, 3, 5])).collect();
+        assert_eq!(v, vec![1, 3, 5]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn empty_sets_reject_everything() {
+        assert!(!OneOf::new(Vec::new()).filter(&1));
+        assert!(!AnyOf::new(Vec::new()).filter(&1));
+    }
+}
@@ -0,0 +1,89 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Hash sharding implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::filter::Filter;
+
+/// Filter which accepts items whose hash falls into shard `index` of `total`
+///
+/// The shards `0..total` partition every set of items: each item is accepted by exactly one of
+/// them.
+///
+/// The hash is computed with a `DefaultHasher` created by `DefaultHasher::new()`, which always
+/// uses the same keys. The assignment is therefore stable across runs of the same binary, but
+/// the algorithm is not guaranteed to stay the same across Rust releases. Do not rely on the
+/// assignment if it has to be persisted.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct Shard {
+    index: u64,
+    total: u64,
+}
+
+impl Shard {
+    /// # Panics
+    ///
+    /// If `index` is not less than `total`.
+    pub fn new(index: u64, total: u64) -> Shard {
+        assert!(index < total, "shard index out of range");
+        Shard { index, total }
+    }
+}
+
+impl<N: Hash> Filter<N> for Shard {
+    fn filter(&self, e: &N) -> bool {
+        let mut hasher = DefaultHasher::new();
+        e.hash(&mut hasher);
+        hasher.finish() % self.total == self.index
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn stable_assignment() {
+        let shard = Shard::new(1, 4);
+        for i in 0..100u32 {
+            assert_eq!(shard.filter(&i), shard.filter(&i));
+            assert_eq!(shard.filter(&i), Shard::new(1, 4).filter(&i));
+        }
+    }
+
+    #[test]
+    fn shards_partition_dataset() {
+        let shards: Vec<Shard> = (0..4).map(|i| Shard::new(i, 4)).collect();
+
+        for item in (0..1000u32).map(|i| format!("item-{}", i)) {
+            let accepted = shards.iter().filter(|s| s.filter(&item)).count();
+            assert_eq!(accepted, 1);
+        }
+
+        for shard in shards.iter() {
+            assert!((0..1000u32).any(|i| shard.filter(&i)));
+        }
+    }
+
+    #[test]
+    fn single_shard_accepts_everything() {
+        let shard = Shard::new(0, 1);
+        assert!((0..100u32).all(|i| shard.filter(&i)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_range() {
+        let _ = Shard::new(4, 4);
+    }
+}
@@ -0,0 +1,65 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Spy implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use crate::filter::Filter;
+
+/// Filter which passes every accepted input to a sink
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct Spy<T, S>(T, S);
+
+impl<T, S> Spy<T, S> {
+    pub fn new(a: T, sink: S) -> Spy<T, S> {
+        Spy(a, sink)
+    }
+}
+
+impl<N, T, S> Filter<N> for Spy<T, S>
+where
+    T: Filter<N>,
+    S: Fn(&N),
+{
+    fn filter(&self, e: &N) -> bool {
+        let accepted = self.0.filter(e);
+        if accepted {
+            (self.1)(e);
+        }
+        accepted
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+
+    use crate::filter::Filter;
+
+    #[test]
+    fn records_only_accepted_inputs() {
+        let seen = RefCell::new(Vec::new());
+        let f = (|&a: &usize| a % 2 == 0).spy(|&a: &usize| seen.borrow_mut().push(a));
+
+        let r: Vec<usize> = (0..7).filter(|a| f.filter(a)).collect();
+
+        assert_eq!(r, vec![0, 2, 4, 6]);
+        assert_eq!(*seen.borrow(), vec![0, 2, 4, 6]);
+    }
+
+    #[test]
+    fn rejected_inputs_are_not_recorded() {
+        let seen = RefCell::new(Vec::new());
+        let f = (|&a: &usize| a > 10).spy(|&a: &usize| seen.borrow_mut().push(a));
+
+        assert!(!f.filter(&1));
+        assert!(!f.filter(&10));
+        assert!(seen.borrow().is_empty());
+    }
+}
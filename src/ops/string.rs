@@ -0,0 +1,231 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! String predicate filter implementations.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use alloc::string::String;
+
+use crate::filter::Filter;
+
+/// Filter which accepts strings that start with the needle
+///
+/// Implements `Filter<&str>` and `Filter<String>`.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct StartsWith {
+    needle: String,
+    ignore_case: bool,
+}
+
+impl StartsWith {
+    pub fn new<S: Into<String>>(needle: S) -> StartsWith {
+        StartsWith {
+            needle: needle.into(),
+            ignore_case: false,
+        }
+    }
+
+    /// Compare case-insensitively
+    pub fn ignore_case(self) -> StartsWith {
+        StartsWith {
+            needle: self.needle.to_lowercase(),
+            ignore_case: true,
+        }
+    }
+
+    fn matches(&self, s: &str) -> bool {
+        if self.ignore_case {
+            s.to_lowercase().starts_with(&self.needle)
+        } else {
+            s.starts_with(&self.needle)
+        }
+    }
+}
+
+impl_bit_operators!(StartsWith,);
+impl_not_operator!(StartsWith,);
+
+impl<'a> Filter<&'a str> for StartsWith {
+    fn filter(&self, e: &&'a str) -> bool {
+        self.matches(e)
+    }
+}
+
+impl Filter<String> for StartsWith {
+    fn filter(&self, e: &String) -> bool {
+        self.matches(e)
+    }
+}
+
+/// Create a filter which accepts strings that start with `needle`
+pub fn starts_with<S: Into<String>>(needle: S) -> StartsWith {
+    StartsWith::new(needle)
+}
+
+/// Filter which accepts strings that end with the needle
+///
+/// Implements `Filter<&str>` and `Filter<String>`.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct EndsWith {
+    needle: String,
+    ignore_case: bool,
+}
+
+impl EndsWith {
+    pub fn new<S: Into<String>>(needle: S) -> EndsWith {
+        EndsWith {
+            needle: needle.into(),
+            ignore_case: false,
+        }
+    }
+
+    /// Compare case-insensitively
+    pub fn ignore_case(self) -> EndsWith {
+        EndsWith {
+            needle: self.needle.to_lowercase(),
+            ignore_case: true,
+        }
+    }
+
+    fn matches(&self, s: &str) -> bool {
+        if self.ignore_case {
+            s.to_lowercase().ends_with(&self.needle)
+        } else {
+            s.ends_with(&self.needle)
+        }
+    }
+}
+
+impl_bit_operators!(EndsWith,);
+impl_not_operator!(EndsWith,);
+
+impl<'a> Filter<&'a str> for EndsWith {
+    fn filter(&self, e: &&'a str) -> bool {
+        self.matches(e)
+    }
+}
+
+impl Filter<String> for EndsWith {
+    fn filter(&self, e: &String) -> bool {
+        self.matches(e)
+    }
+}
+
+/// Create a filter which accepts strings that end with `needle`
+pub fn ends_with<S: Into<String>>(needle: S) -> EndsWith {
+    EndsWith::new(needle)
+}
+
+/// Filter which accepts strings that contain the needle
+///
+/// Implements `Filter<&str>` and `Filter<String>`.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct Contains {
+    needle: String,
+    ignore_case: bool,
+}
+
+impl Contains {
+    pub fn new<S: Into<String>>(needle: S) -> Contains {
+        Contains {
+            needle: needle.into(),
+            ignore_case: false,
+        }
+    }
+
+    /// Compare case-insensitively
+    pub fn ignore_case(self) -> Contains {
+        Contains {
+            needle: self.needle.to_lowercase(),
+            ignore_case: true,
+        }
+    }
+
+    fn matches(&self, s: &str) -> bool {
+        if self.ignore_case {
+            s.to_lowercase().contains(&self.needle)
+        } else {
+            s.contains(&self.needle)
+        }
+    }
+}
+
+impl_bit_operators!(Contains,);
+impl_not_operator!(Contains,);
+
+impl<'a> Filter<&'a str> for Contains {
+    fn filter(&self, e: &&'a str) -> bool {
+        self.matches(e)
+    }
+}
+
+impl Filter<String> for Contains {
+    fn filter(&self, e: &String) -> bool {
+        self.matches(e)
+    }
+}
+
+/// Create a filter which accepts strings that contain `needle`
+pub fn contains<S: Into<String>>(needle: S) -> Contains {
+    Contains::new(needle)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::iter::FilterWith;
+
+    #[test]
+    fn starts_with_prefix() {
+        assert!(StartsWith::new("/etc").filter(&"/etc/passwd"));
+        assert!(!StartsWith::new("/etc").filter(&"/usr/etc"));
+        assert!(StartsWith::new("/etc").filter(&String::from("/etc/hosts")));
+    }
+
+    #[test]
+    fn ends_with_suffix() {
+        assert!(ends_with(".rs").filter(&"main.rs"));
+        assert!(!ends_with(".rs").filter(&"main.rs.bak"));
+    }
+
+    #[test]
+    fn contains_needle() {
+        assert!(contains("error").filter(&"an error occurred"));
+        assert!(!contains("error").filter(&"all good"));
+    }
+
+    #[test]
+    fn ignore_case() {
+        assert!(!StartsWith::new("/ETC").filter(&"/etc/passwd"));
+        assert!(StartsWith::new("/ETC").ignore_case().filter(&"/etc/passwd"));
+        assert!(ends_with(".RS").ignore_case().filter(&"Main.rs"));
+        assert!(contains("Error").ignore_case().filter(&"an ERROR occurred"));
+    }
+
+    #[test]
+    fn exclude_prefix() {
+        let v: Vec<&str> = vec!["/etc/passwd", "/home/user", "/etc/hosts", "/usr/bin"]
+            .into_iter()
+            .filter_with(!StartsWith::new("/etc"))
+            .collect();
+
+        assert_eq!(v, vec!["/home/user", "/usr/bin"]);
+    }
+
+    #[test]
+    fn combination() {
+        let f = starts_with("/etc") & !ends_with(".conf");
+
+        assert!(f.filter(&"/etc/passwd"));
+        assert!(!f.filter(&"/etc/resolv.conf"));
+        assert!(!f.filter(&"/usr/bin"));
+    }
+}
@@ -0,0 +1,101 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Threshold implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::filter::Filter;
+
+/// Filter which passes if at least `k` of its sub-filters pass
+///
+/// With `k == 0` it always passes, with `k` greater than the number of sub-filters it never
+/// passes. Sub-filters are evaluated in order until `k` of them passed.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+pub struct Threshold<N>(usize, Vec<Box<dyn Filter<N>>>);
+
+impl<N> Threshold<N> {
+    pub fn new(k: usize, filters: Vec<Box<dyn Filter<N>>>) -> Threshold<N> {
+        Threshold(k, filters)
+    }
+}
+
+impl_boxed_debug!(Threshold, N; filters: 1, k: 0);
+
+impl<N> Filter<N> for Threshold<N> {
+    fn filter(&self, e: &N) -> bool {
+        self.0 == 0
+            || self
+                .1
+                .iter()
+                .filter(|f| f.filter(e))
+                .nth(self.0 - 1)
+                .is_some()
+    }
+}
+
+/// Create a filter which passes if at least `k` of the `filters` pass
+///
+/// ```
+/// use filters::filter::Filter;
+/// use filters::ops::threshold::at_least;
+///
+/// let f = at_least(2, vec![
+///     Box::new(|&a: &usize| a > 5),
+///     Box::new(|&a: &usize| a % 2 == 0),
+///     Box::new(|&a: &usize| a < 20),
+/// ]);
+///
+/// assert!(f.filter(&4));
+/// assert!(!f.filter(&25));
+/// ```
+pub fn at_least<N>(k: usize, filters: Vec<Box<dyn Filter<N>>>) -> Threshold<N> {
+    Threshold::new(k, filters)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::checks;
+
+    #[test]
+    fn k_of_n() {
+        let f = at_least(2, checks());
+
+        assert!(!f.filter(&1));
+        assert!(!f.filter(&2));
+        assert!(f.filter(&6));
+        assert!(!f.filter(&7));
+        assert!(f.filter(&8));
+        assert!(f.filter(&9));
+    }
+
+    #[test]
+    fn k_zero_always_passes() {
+        let f = at_least(0, checks());
+        assert!(f.filter(&1));
+
+        let f: Threshold<usize> = at_least(0, Vec::new());
+        assert!(f.filter(&1));
+    }
+
+    #[test]
+    fn k_greater_than_n_never_passes() {
+        let f = at_least(4, checks());
+        assert!(!f.filter(&12));
+    }
+
+    #[test]
+    fn k_equal_to_n_is_and() {
+        let f = at_least(3, checks());
+        assert!(f.filter(&12));
+        assert!(!f.filter(&8));
+    }
+}
@@ -0,0 +1,76 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Toggle implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use core::cell::Cell;
+
+use crate::filter::Filter;
+
+/// Filter whose decision can be inverted at runtime
+///
+/// The flag is stored in a `Cell`, so it can be flipped through a shared reference.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct Toggle<F>(F, Cell<bool>);
+
+impl<F> Toggle<F> {
+    pub fn new(a: F) -> Toggle<F> {
+        Toggle(a, Cell::new(false))
+    }
+
+    /// Set whether the decision of the inner filter is inverted
+    pub fn set_inverted(&self, inverted: bool) {
+        self.1.set(inverted)
+    }
+
+    /// Whether the decision of the inner filter is inverted
+    pub fn is_inverted(&self) -> bool {
+        self.1.get()
+    }
+}
+
+impl<N, F: Filter<N>> Filter<N> for Toggle<F> {
+    fn filter(&self, e: &N) -> bool {
+        self.0.filter(e) ^ self.1.get()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::Filter;
+
+    #[test]
+    fn flips() {
+        let f = (|&a: &usize| a > 5).toggleable();
+
+        assert!(!f.is_inverted());
+        assert!(f.filter(&7));
+        assert!(!f.filter(&3));
+
+        f.set_inverted(true);
+        assert!(f.is_inverted());
+        assert!(!f.filter(&7));
+        assert!(f.filter(&3));
+
+        f.set_inverted(false);
+        assert!(f.filter(&7));
+    }
+
+    #[test]
+    fn flips_while_borrowed() {
+        let f = (|&a: &usize| a % 2 == 0).toggleable();
+        let g = f.by_ref().and(|&a: &usize| a < 10);
+
+        assert_eq!((0..20).filter(|a| g.filter(a)).count(), 5);
+        f.set_inverted(true);
+        assert_eq!((0..20).filter(|a| g.filter(a)).count(), 5);
+        assert!(g.filter(&3));
+    }
+}
@@ -0,0 +1,152 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Tracing implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use crate::filter::Filter;
+
+/// Filter which wraps every decision of the inner filter in a `tracing` span
+///
+/// With the `tracing` feature enabled, every call opens a `TRACE` level span named `filter`
+/// with the fields `name`, set to the configured name, and `decision`, recorded once the inner
+/// filter returned. Without the feature, this filter only delegates to the inner filter.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+#[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+pub struct Traced<T>(T, &'static str);
+
+impl<T> Traced<T> {
+    pub fn new(a: T, name: &'static str) -> Traced<T> {
+        Traced(a, name)
+    }
+}
+
+impl<N, T> Filter<N> for Traced<T>
+where
+    T: Filter<N>,
+{
+    #[cfg(feature = "tracing")]
+    fn filter(&self, e: &N) -> bool {
+        let span = tracing::trace_span!("filter", name = self.1, decision = tracing::field::Empty);
+        let _entered = span.enter();
+
+        let decision = self.0.filter(e);
+        span.record("decision", decision);
+        decision
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    fn filter(&self, e: &N) -> bool {
+        self.0.filter(e)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tracing")]
+mod test {
+    use std::fmt;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use crate::filter::Filter;
+
+    /// Spans in creation order, as `(name, decision)`
+    type Recorded = Arc<Mutex<Vec<(String, Option<bool>)>>>;
+
+    struct Recorder {
+        next_id: AtomicU64,
+        spans: Recorded,
+    }
+
+    struct FieldVisitor<'a>(&'a mut (String, Option<bool>));
+
+    impl<'a> Visit for FieldVisitor<'a> {
+        fn record_bool(&mut self, field: &Field, value: bool) {
+            if field.name() == "decision" {
+                (self.0).1 = Some(value);
+            }
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "name" {
+                (self.0).0 = value.to_string();
+            }
+        }
+
+        fn record_debug(&mut self, _: &Field, _: &dyn fmt::Debug) {}
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut entry = (String::new(), None);
+            span.record(&mut FieldVisitor(&mut entry));
+            self.spans.lock().unwrap().push(entry);
+            Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst))
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let mut spans = self.spans.lock().unwrap();
+            let entry = &mut spans[span.into_u64() as usize - 1];
+            values.record(&mut FieldVisitor(entry));
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, _: &Event<'_>) {}
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    fn record<F: FnOnce()>(f: F) -> Vec<(String, Option<bool>)> {
+        let spans = Recorded::default();
+        let recorder = Recorder {
+            next_id: AtomicU64::new(1),
+            spans: spans.clone(),
+        };
+
+        tracing::subscriber::with_default(recorder, f);
+
+        let spans = spans.lock().unwrap();
+        spans.clone()
+    }
+
+    #[test]
+    fn records_decisions_of_composed_filter() {
+        let f = (|&a: &usize| a > 5)
+            .traced("gt")
+            .and((|&a: &usize| a < 10).traced("lt"))
+            .traced("both");
+
+        let spans = record(|| assert!(!f.filter(&3)));
+        assert_eq!(
+            spans,
+            vec![
+                ("both".to_string(), Some(false)),
+                ("gt".to_string(), Some(false)),
+            ]
+        );
+
+        let spans = record(|| assert!(f.filter(&7)));
+        assert_eq!(
+            spans,
+            vec![
+                ("both".to_string(), Some(true)),
+                ("gt".to_string(), Some(true)),
+                ("lt".to_string(), Some(true)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,74 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Transition table filter implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::filter::Filter;
+
+/// Filter over `(prev, next)` pairs which only accepts transitions listed in a table
+///
+/// A `prev` state without an entry in the table has no allowed transitions.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct AllowedTransition<S>(HashMap<S, HashSet<S>>);
+
+impl<S> AllowedTransition<S> {
+    pub fn new(table: HashMap<S, HashSet<S>>) -> AllowedTransition<S> {
+        AllowedTransition(table)
+    }
+}
+
+impl<S> Filter<(S, S)> for AllowedTransition<S>
+where
+    S: Eq + Hash,
+{
+    fn filter(&self, (prev, next): &(S, S)) -> bool {
+        self.0
+            .get(prev)
+            .map(|allowed| allowed.contains(next))
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn table() -> AllowedTransition<&'static str> {
+        let mut table = HashMap::new();
+        table.insert("open", vec!["closed", "locked"].into_iter().collect());
+        table.insert("closed", vec!["open"].into_iter().collect());
+        AllowedTransition::new(table)
+    }
+
+    #[test]
+    fn allowed_transitions() {
+        let t = table();
+        assert!(t.filter(&("open", "closed")));
+        assert!(t.filter(&("open", "locked")));
+        assert!(t.filter(&("closed", "open")));
+    }
+
+    #[test]
+    fn disallowed_transitions() {
+        let t = table();
+        assert!(!t.filter(&("closed", "locked")));
+        assert!(!t.filter(&("open", "open")));
+    }
+
+    #[test]
+    fn unknown_prev_is_rejected() {
+        let t = table();
+        assert!(!t.filter(&("locked", "open")));
+    }
+}
@@ -0,0 +1,96 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! UUID filter implementations.
+//!
+//! Only available with the `uuid` feature.
+//!
+use ::uuid::{Uuid, Variant};
+
+use crate::filter::Filter;
+
+/// Filter which accepts UUIDs of the given version number
+///
+/// ```
+/// use filters::filter::Filter;
+/// use filters::ops::uuid::UuidVersion;
+///
+/// let v4 = "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap();
+///
+/// assert!(UuidVersion::new(4).filter(&v4));
+/// assert!(!UuidVersion::new(1).filter(&v4));
+/// ```
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct UuidVersion(usize);
+
+impl UuidVersion {
+    pub fn new(version: usize) -> UuidVersion {
+        UuidVersion(version)
+    }
+}
+
+impl Filter<Uuid> for UuidVersion {
+    fn filter(&self, e: &Uuid) -> bool {
+        e.get_version_num() == self.0
+    }
+}
+
+/// Filter which accepts UUIDs of the given variant
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct UuidVariant(Variant);
+
+impl UuidVariant {
+    pub fn new(variant: Variant) -> UuidVariant {
+        UuidVariant(variant)
+    }
+}
+
+impl Filter<Uuid> for UuidVariant {
+    fn filter(&self, e: &Uuid) -> bool {
+        e.get_variant() == self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn v1() -> Uuid {
+        "c232ab00-9414-11ec-b3c8-9f6bdeced846".parse().unwrap()
+    }
+
+    fn v4() -> Uuid {
+        "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap()
+    }
+
+    #[test]
+    fn version() {
+        let only_v4 = UuidVersion::new(4);
+        assert!(only_v4.filter(&v4()));
+        assert!(!only_v4.filter(&v1()));
+
+        let only_v1 = UuidVersion::new(1);
+        assert!(only_v1.filter(&v1()));
+        assert!(!only_v1.filter(&v4()));
+    }
+
+    #[test]
+    fn variant() {
+        let rfc = UuidVariant::new(Variant::RFC4122);
+        assert!(rfc.filter(&v1()));
+        assert!(rfc.filter(&v4()));
+        assert!(!rfc.filter(&Uuid::nil()));
+    }
+
+    #[test]
+    fn combined() {
+        let f = UuidVersion::new(4).and(UuidVariant::new(Variant::RFC4122));
+        assert!(f.filter(&v4()));
+        assert!(!f.filter(&v1()));
+    }
+}
@@ -0,0 +1,112 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Weighted implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use alloc::vec::Vec;
+
+use crate::filter::{BoxedFilter, Filter};
+
+/// Filter which passes if the summed weights of the passing sub-filters reach a threshold
+///
+/// Weights can be negative, so a sub-filter can be used as a penalty. All sub-filters are
+/// evaluated. The filter passes if the score is greater than or equal to the threshold.
+#[must_use = "filters are lazy and do nothing unless consumed"]
+pub struct Weighted<N>(Vec<(f64, BoxedFilter<N>)>, f64);
+
+impl<N> Weighted<N> {
+    pub fn new(filters: Vec<(f64, BoxedFilter<N>)>, threshold: f64) -> Weighted<N> {
+        Weighted(filters, threshold)
+    }
+
+    /// The summed weights of the sub-filters which pass for `e`
+    pub fn score(&self, e: &N) -> f64 {
+        self.0
+            .iter()
+            .filter(|(_, f)| f.filter(e))
+            .map(|(weight, _)| weight)
+            .sum()
+    }
+}
+
+impl_boxed_debug!(Weighted, N; filters: 0, threshold: 1);
+
+impl<N> Filter<N> for Weighted<N> {
+    fn filter(&self, e: &N) -> bool {
+        self.score(e) >= self.1
+    }
+}
+
+/// Create a filter which passes if the summed weights of the passing `filters` reach `threshold`
+///
+/// ```
+/// use filters::filter::Filter;
+/// use filters::ops::weighted::weighted;
+///
+/// let f = weighted(vec![
+///     (1.0, Box::new(|&a: &usize| a > 5)),
+///     (1.0, Box::new(|&a: &usize| a % 2 == 0)),
+///     (-2.0, Box::new(|&a: &usize| a > 100)),
+/// ], 2.0);
+///
+/// assert!(!f.filter(&7));
+/// assert!(f.filter(&8));
+/// assert!(!f.filter(&108));
+/// ```
+pub fn weighted<N>(filters: Vec<(f64, BoxedFilter<N>)>, threshold: f64) -> Weighted<N> {
+    Weighted::new(filters, threshold)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::checks;
+
+    fn scoring(threshold: f64) -> Weighted<usize> {
+        let weights = [0.5, 1.5, -1.0];
+        weighted(weights.iter().copied().zip(checks()).collect(), threshold)
+    }
+
+    #[test]
+    fn score() {
+        let f = scoring(1.0);
+
+        assert_eq!(f.score(&1), 0.0);
+        assert_eq!(f.score(&3), -1.0);
+        assert_eq!(f.score(&8), 2.0);
+        assert_eq!(f.score(&12), 1.0);
+    }
+
+    #[test]
+    fn threshold_boundary() {
+        let f = scoring(1.0);
+
+        assert!(!f.filter(&1));
+        assert!(!f.filter(&3));
+        assert!(!f.filter(&7));
+        assert!(f.filter(&8));
+        assert!(f.filter(&12));
+        assert!(f.filter(&2));
+    }
+
+    #[test]
+    fn negative_threshold() {
+        let f = scoring(-1.0);
+
+        assert!(f.filter(&3));
+        assert!(f.filter(&1));
+        assert!(!scoring(-0.5).filter(&3));
+    }
+
+    #[test]
+    fn empty() {
+        assert!(weighted::<usize>(Vec::new(), 0.0).filter(&1));
+        assert!(!weighted::<usize>(Vec::new(), 0.1).filter(&1));
+    }
+}
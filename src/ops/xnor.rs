@@ -0,0 +1,26 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! XNOR implementation.
+//!
+//! Will be automatically included when including `filter::Filter`, so importing this module
+//! shouldn't be necessary.
+//!
+use crate::filter::Filter;
+
+#[must_use = "filters are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct XNor<T, U>(T, U);
+
+impl<T, U> XNor<T, U> {
+    pub const fn new(a: T, b: U) -> XNor<T, U> {
+        XNor(a, b)
+    }
+}
+
+impl_operators!(XNor, self e { !(self.0.filter(e) ^ self.1.filter(e)) }, T, U);
+impl_bit_operators!(XNor, T, U);
+impl_not_operator!(XNor, T, U);
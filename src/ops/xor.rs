@@ -12,13 +12,15 @@
 use crate::filter::Filter;
 
 #[must_use = "filters are lazy and do nothing unless consumed"]
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct XOr<T, U>(T, U);
 
 impl<T, U> XOr<T, U> {
-    pub fn new(a: T, b: U) -> XOr<T, U> {
+    pub const fn new(a: T, b: U) -> XOr<T, U> {
         XOr(a, b)
     }
 }
 
 impl_operators!(XOr, self e { self.0.filter(e) ^ self.1.filter(e) }, T, U);
+impl_bit_operators!(XOr, T, U);
+impl_not_operator!(XOr, T, U);
@@ -0,0 +1,99 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Filtering rayon parallel iterators
+//!
+//! The filter is shared between the threads of the rayon thread pool, so it has to be `Sync` and
+//! `Send`. Filters with interior mutability (for example `Cached` or `Counting`) are not `Sync`
+//! and cannot be used here.
+//!
+//! ```
+//! use rayon::prelude::*;
+//! use filters::filter::Filter;
+//! use filters::par_iter::ParFilterWith;
+//!
+//! let v: Vec<usize> = (0..100)
+//!     .into_par_iter()
+//!     .par_filter_with((|&a: &usize| a > 5).and(|&a: &usize| a < 10))
+//!     .collect();
+//!
+//! assert_eq!(v, vec![6, 7, 8, 9]);
+//! ```
+//!
+//! Only available with the `rayon` feature.
+//!
+use ::rayon::iter::plumbing::UnindexedConsumer;
+use ::rayon::iter::ParallelIterator;
+
+use crate::filter::Filter;
+
+pub struct ParFilteredIterator<F, I>(F, I);
+
+impl<F, I> ParallelIterator for ParFilteredIterator<F, I>
+where
+    F: Filter<I::Item> + Sync + Send,
+    I: ParallelIterator,
+{
+    type Item = I::Item;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        let filter = self.0;
+        self.1
+            .filter(move |e| filter.filter(e))
+            .drive_unindexed(consumer)
+    }
+}
+
+pub trait ParFilterWith<T, F>: ParallelIterator<Item = T> + Sized
+where
+    F: Filter<T> + Sync + Send,
+{
+    /// Yield only the items which pass the filter, filtering in parallel
+    fn par_filter_with(self, f: F) -> ParFilteredIterator<F, Self>;
+}
+
+impl<I, T, F> ParFilterWith<T, F> for I
+where
+    I: ParallelIterator<Item = T>,
+    F: Filter<T> + Sync + Send,
+{
+    fn par_filter_with(self, f: F) -> ParFilteredIterator<F, Self> {
+        ParFilteredIterator(f, self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::rayon::prelude::*;
+
+    use super::*;
+    use crate::iter::FilterWith;
+
+    #[test]
+    fn same_as_sequential() {
+        let f = || (|&a: &u64| a % 7 == 3).or(|&a: &u64| a.count_ones() == 4);
+        let v: Vec<u64> = (0..100_000).collect();
+
+        let mut par: Vec<u64> = v.par_iter().cloned().par_filter_with(f()).collect();
+        par.sort_unstable();
+        let seq: Vec<u64> = v.into_iter().filter_with(f()).collect();
+
+        assert!(!seq.is_empty());
+        assert_eq!(par, seq);
+    }
+
+    #[test]
+    fn empty() {
+        let v: Vec<u64> = Vec::new()
+            .into_par_iter()
+            .par_filter_with(|_: &u64| true)
+            .collect();
+        assert!(v.is_empty());
+    }
+}
@@ -0,0 +1,541 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Serializable filter trees
+//!
+//! Closures cannot be serialized, so filter trees which should be stored, for example in a
+//! configuration file, can be built from `FilterExpr` instead. Each variant is serialized as a
+//! map with the lowercase variant name as the only key:
+//!
+//! ```
+//! use filters::serde_expr::FilterExpr;
+//! use filters::filter::Filter;
+//!
+//! let f: FilterExpr<usize> = serde_json::from_str(r#"{"and":[{"gt":5},{"lt":15}]}"#).unwrap();
+//!
+//! assert!(!f.filter(&5));
+//! assert!(f.filter(&10));
+//! assert!(!f.filter(&15));
+//! ```
+//!
+//! Only available with the `serde` feature.
+//!
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::filter::Filter;
+
+/// A filter tree which can be serialized and deserialized
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FilterExpr<T> {
+    /// Accepts if all filters accept, or if there are none
+    And(Vec<FilterExpr<T>>),
+    /// Accepts if any filter accepts, but not if there are none
+    Or(Vec<FilterExpr<T>>),
+    /// Inverts the filter
+    Not(Box<FilterExpr<T>>),
+    /// Accepts if exactly one of the two filters accepts
+    Xor(Box<FilterExpr<T>>, Box<FilterExpr<T>>),
+    /// Constant decision
+    Bool(bool),
+    /// Accepts values greater than the bound
+    Gt(T),
+    /// Accepts values greater than or equal to the bound
+    Ge(T),
+    /// Accepts values less than the bound
+    Lt(T),
+    /// Accepts values less than or equal to the bound
+    Le(T),
+    /// Accepts values equal to the bound
+    Eq(T),
+}
+
+impl<T: PartialOrd> Filter<T> for FilterExpr<T> {
+    fn filter(&self, e: &T) -> bool {
+        match self {
+            FilterExpr::And(v) => v.iter().all(|f| f.filter(e)),
+            FilterExpr::Or(v) => v.iter().any(|f| f.filter(e)),
+            FilterExpr::Not(f) => !f.filter(e),
+            FilterExpr::Xor(a, b) => a.filter(e) ^ b.filter(e),
+            FilterExpr::Bool(b) => *b,
+            FilterExpr::Gt(bound) => e > bound,
+            FilterExpr::Ge(bound) => e >= bound,
+            FilterExpr::Lt(bound) => e < bound,
+            FilterExpr::Le(bound) => e <= bound,
+            FilterExpr::Eq(bound) => e == bound,
+        }
+    }
+}
+
+/// The decision of a node in a filter tree, together with the decisions of its children
+///
+/// Created by `FilterExpr::filter_explain()`. Printing it with `{}` shows one node per line,
+/// indented by depth.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    /// The node, for example `and` or `gt(5)`
+    pub node: String,
+    /// Whether the node passed
+    pub passed: bool,
+    /// The explanations of the children of the node
+    pub children: Vec<Explanation>,
+}
+
+impl Explanation {
+    fn fmt_indented(&self, f: &mut fmt::Formatter, depth: usize) -> fmt::Result {
+        let verdict = if self.passed { "pass" } else { "fail" };
+        writeln!(
+            f,
+            "{:indent$}{} {}",
+            "",
+            verdict,
+            self.node,
+            indent = depth * 2
+        )?;
+        self.children
+            .iter()
+            .try_for_each(|c| c.fmt_indented(f, depth + 1))
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+impl<T: PartialOrd + fmt::Debug> FilterExpr<T> {
+    /// Filter `e` and explain the decision
+    ///
+    /// Unlike `filter()`, this evaluates all nodes of the tree, so the explanation also contains
+    /// the decisions of nodes which do not influence the result.
+    ///
+    /// ```
+    /// use filters::serde_expr::FilterExpr;
+    ///
+    /// let f: FilterExpr<usize> = FilterExpr::And(vec![FilterExpr::Gt(5), FilterExpr::Lt(15)]);
+    /// let (passed, explanation) = f.filter_explain(&20);
+    ///
+    /// assert!(!passed);
+    /// assert_eq!(explanation.to_string(), "fail and\n  pass gt(5)\n  fail lt(15)\n");
+    /// ```
+    pub fn filter_explain(&self, e: &T) -> (bool, Explanation) {
+        let explanation = self.explain(e);
+        (explanation.passed, explanation)
+    }
+
+    fn explain(&self, e: &T) -> Explanation {
+        let (node, children) = match self {
+            FilterExpr::And(v) => ("and".to_string(), v.iter().map(|f| f.explain(e)).collect()),
+            FilterExpr::Or(v) => ("or".to_string(), v.iter().map(|f| f.explain(e)).collect()),
+            FilterExpr::Not(f) => ("not".to_string(), vec![f.explain(e)]),
+            FilterExpr::Xor(a, b) => ("xor".to_string(), vec![a.explain(e), b.explain(e)]),
+            FilterExpr::Bool(b) => (format!("bool({})", b), Vec::new()),
+            FilterExpr::Gt(bound) => (format!("gt({:?})", bound), Vec::new()),
+            FilterExpr::Ge(bound) => (format!("ge({:?})", bound), Vec::new()),
+            FilterExpr::Lt(bound) => (format!("lt({:?})", bound), Vec::new()),
+            FilterExpr::Le(bound) => (format!("le({:?})", bound), Vec::new()),
+            FilterExpr::Eq(bound) => (format!("eq({:?})", bound), Vec::new()),
+        };
+
+        let passed = match self {
+            FilterExpr::And(_) => children.iter().all(|c: &Explanation| c.passed),
+            FilterExpr::Or(_) => children.iter().any(|c: &Explanation| c.passed),
+            FilterExpr::Not(_) => !children[0].passed,
+            FilterExpr::Xor(..) => children[0].passed ^ children[1].passed,
+            leaf => leaf.filter(e),
+        };
+
+        Explanation {
+            node,
+            passed,
+            children,
+        }
+    }
+}
+
+/// A filter tree together with its number of nodes
+type Counted<T> = (FilterExpr<T>, usize);
+
+impl<T: Clone> FilterExpr<T> {
+    /// Simplify the filter tree without changing its decisions
+    ///
+    /// Double negations are removed, negations are moved through `And` and `Or` by De Morgan's
+    /// laws where this makes the tree smaller, nested `And`s and `Or`s are flattened and `Bool`
+    /// leaves are folded into their parents. Comparison leaves are never rewritten, so the result
+    /// is equivalent for types which are only partially ordered as well.
+    ///
+    /// ```
+    /// use filters::serde_expr::FilterExpr;
+    ///
+    /// let f: FilterExpr<usize> = FilterExpr::Not(Box::new(FilterExpr::And(vec![
+    ///     FilterExpr::Bool(true),
+    ///     FilterExpr::Not(Box::new(FilterExpr::Gt(5))),
+    ///     FilterExpr::Not(Box::new(FilterExpr::Lt(2))),
+    /// ])));
+    ///
+    /// assert_eq!(
+    ///     f.optimize(),
+    ///     FilterExpr::Or(vec![FilterExpr::Gt(5), FilterExpr::Lt(2)])
+    /// );
+    /// ```
+    pub fn optimize(self) -> FilterExpr<T> {
+        let ((pos, _), _) = self.optimized();
+        pos
+    }
+
+    /// The number of nodes in the filter tree
+    pub fn node_count(&self) -> usize {
+        match self {
+            FilterExpr::And(v) | FilterExpr::Or(v) => {
+                1 + v.iter().map(|f| f.node_count()).sum::<usize>()
+            }
+            FilterExpr::Not(f) => 1 + f.node_count(),
+            FilterExpr::Xor(a, b) => 1 + a.node_count() + b.node_count(),
+            _ => 1,
+        }
+    }
+
+    /// The optimized tree and the optimized negation of the tree, each with its node count
+    ///
+    /// The node counts are passed up with the trees, so choosing the smaller negation does not
+    /// need to walk the subtrees again.
+    fn optimized(self) -> (Counted<T>, Counted<T>) {
+        match self {
+            FilterExpr::Not(f) => {
+                let (pos, neg) = f.optimized();
+                (neg, pos)
+            }
+            FilterExpr::Bool(b) => ((FilterExpr::Bool(b), 1), (FilterExpr::Bool(!b), 1)),
+            FilterExpr::And(v) => {
+                let (pos, neg): (Vec<_>, Vec<_>) = v.into_iter().map(FilterExpr::optimized).unzip();
+                let pos = FilterExpr::fold(pos, true);
+                let neg = FilterExpr::fold(neg, false);
+                let neg = FilterExpr::smaller_negation(&pos, neg);
+                (pos, neg)
+            }
+            FilterExpr::Or(v) => {
+                let (pos, neg): (Vec<_>, Vec<_>) = v.into_iter().map(FilterExpr::optimized).unzip();
+                let pos = FilterExpr::fold(pos, false);
+                let neg = FilterExpr::fold(neg, true);
+                let neg = FilterExpr::smaller_negation(&pos, neg);
+                (pos, neg)
+            }
+            FilterExpr::Xor(a, b) => {
+                let (a, not_a) = a.optimized();
+                let (b, not_b) = b.optimized();
+
+                match (a, b) {
+                    ((FilterExpr::Bool(true), _), b) => (not_b, b),
+                    ((FilterExpr::Bool(false), _), b) => (b, not_b),
+                    (a, (FilterExpr::Bool(true), _)) => (not_a, a),
+                    (a, (FilterExpr::Bool(false), _)) => (a, not_a),
+                    ((a, a_nodes), (b, b_nodes)) => {
+                        // The negation of `a ^ b` is `!a ^ b` or `!(a ^ b)`, which has one node
+                        // more than `a ^ b`
+                        let neg_nodes = 1 + not_a.1 + b_nodes;
+                        let neg = if neg_nodes < a_nodes + b_nodes + 2 {
+                            Some(FilterExpr::Xor(Box::new(not_a.0), Box::new(b.clone())))
+                        } else {
+                            None
+                        };
+                        let pos = (
+                            FilterExpr::Xor(Box::new(a), Box::new(b)),
+                            1 + a_nodes + b_nodes,
+                        );
+                        let neg = match neg {
+                            Some(neg) => (neg, neg_nodes),
+                            None => (FilterExpr::negate(pos.0.clone()), pos.1 + 1),
+                        };
+                        (pos, neg)
+                    }
+                }
+            }
+            leaf => {
+                let neg = FilterExpr::Not(Box::new(leaf.clone()));
+                ((leaf, 1), (neg, 2))
+            }
+        }
+    }
+
+    /// Build an `And` (or an `Or`) of already optimized filters, folding `Bool` leaves
+    fn fold(filters: Vec<Counted<T>>, and: bool) -> Counted<T> {
+        let mut folded = Vec::with_capacity(filters.len());
+        let mut nodes = 0;
+
+        for (f, n) in filters {
+            match f {
+                FilterExpr::And(v) if and => {
+                    folded.extend(v);
+                    nodes += n - 1;
+                }
+                FilterExpr::Or(v) if !and => {
+                    folded.extend(v);
+                    nodes += n - 1;
+                }
+                FilterExpr::Bool(b) if b == and => {}
+                FilterExpr::Bool(b) => return (FilterExpr::Bool(b), 1),
+                f => {
+                    folded.push(f);
+                    nodes += n;
+                }
+            }
+        }
+
+        match folded.len() {
+            0 => (FilterExpr::Bool(and), 1),
+            1 => (folded.remove(0), nodes),
+            _ if and => (FilterExpr::And(folded), 1 + nodes),
+            _ => (FilterExpr::Or(folded), 1 + nodes),
+        }
+    }
+
+    fn negate(f: FilterExpr<T>) -> FilterExpr<T> {
+        match f {
+            FilterExpr::Bool(b) => FilterExpr::Bool(!b),
+            FilterExpr::Not(f) => *f,
+            f => FilterExpr::Not(Box::new(f)),
+        }
+    }
+
+    /// `neg` if it has fewer nodes than the negation of `pos`, the negation of `pos` otherwise
+    ///
+    /// `pos` is only cloned if its negation is kept.
+    fn smaller_negation(pos: &Counted<T>, neg: Counted<T>) -> Counted<T> {
+        let not_pos_nodes = match pos.0 {
+            FilterExpr::Bool(_) => 1,
+            FilterExpr::Not(_) => pos.1 - 1,
+            _ => pos.1 + 1,
+        };
+
+        if neg.1 < not_pos_nodes {
+            neg
+        } else {
+            (FilterExpr::negate(pos.0.clone()), not_pos_nodes)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn composed() -> FilterExpr<i64> {
+        FilterExpr::Or(vec![
+            FilterExpr::And(vec![FilterExpr::Gt(5), FilterExpr::Lt(15)]),
+            FilterExpr::Not(Box::new(FilterExpr::Ge(-10))),
+            FilterExpr::Xor(
+                Box::new(FilterExpr::Eq(20)),
+                Box::new(FilterExpr::Bool(false)),
+            ),
+        ])
+    }
+
+    #[test]
+    fn filter() {
+        let f = composed();
+        let v: Vec<i64> = (-15..25).filter(|x| f.filter(x)).collect();
+
+        assert_eq!(
+            v,
+            vec![-15, -14, -13, -12, -11, 6, 7, 8, 9, 10, 11, 12, 13, 14, 20]
+        );
+    }
+
+    #[test]
+    fn json_format() {
+        let f: FilterExpr<i64> = FilterExpr::And(vec![FilterExpr::Gt(5), FilterExpr::Lt(15)]);
+
+        assert_eq!(
+            serde_json::to_string(&f).unwrap(),
+            r#"{"and":[{"gt":5},{"lt":15}]}"#
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let f = composed();
+        let json = serde_json::to_string(&f).unwrap();
+        let g: FilterExpr<i64> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(f, g);
+        for x in -15..25 {
+            assert_eq!(f.filter(&x), g.filter(&x));
+        }
+    }
+
+    #[test]
+    fn empty_and_or() {
+        assert!(FilterExpr::And(vec![]).filter(&1));
+        assert!(!FilterExpr::Or(vec![]).filter(&1));
+    }
+
+    fn not<T>(f: FilterExpr<T>) -> FilterExpr<T> {
+        FilterExpr::Not(Box::new(f))
+    }
+
+    fn assert_optimizes(f: FilterExpr<i64>, expected: FilterExpr<i64>) {
+        let ((g, g_nodes), (not_g, not_g_nodes)) = f.clone().optimized();
+        assert_eq!(g.node_count(), g_nodes);
+        assert_eq!(not_g.node_count(), not_g_nodes);
+
+        for x in -20..30 {
+            assert_eq!(f.filter(&x), g.filter(&x), "decision differs for {}", x);
+        }
+        assert!(g.node_count() < f.node_count());
+        assert_eq!(g, expected);
+    }
+
+    #[test]
+    fn optimize_double_negation() {
+        assert_optimizes(not(not(FilterExpr::Gt(5))), FilterExpr::Gt(5));
+        assert_optimizes(not(not(not(FilterExpr::Gt(5)))), not(FilterExpr::Gt(5)));
+    }
+
+    #[test]
+    fn optimize_de_morgan() {
+        assert_optimizes(
+            not(FilterExpr::And(vec![
+                not(FilterExpr::Gt(5)),
+                not(FilterExpr::Lt(-5)),
+            ])),
+            FilterExpr::Or(vec![FilterExpr::Gt(5), FilterExpr::Lt(-5)]),
+        );
+        assert_optimizes(
+            not(FilterExpr::Or(vec![
+                not(FilterExpr::Gt(5)),
+                not(FilterExpr::Lt(15)),
+            ])),
+            FilterExpr::And(vec![FilterExpr::Gt(5), FilterExpr::Lt(15)]),
+        );
+    }
+
+    #[test]
+    fn optimize_constants() {
+        assert_optimizes(
+            FilterExpr::And(vec![FilterExpr::Bool(true), FilterExpr::Gt(5)]),
+            FilterExpr::Gt(5),
+        );
+        assert_optimizes(
+            FilterExpr::Or(vec![FilterExpr::Bool(false), FilterExpr::Gt(5)]),
+            FilterExpr::Gt(5),
+        );
+        assert_optimizes(
+            FilterExpr::And(vec![FilterExpr::Gt(5), not(FilterExpr::Bool(true))]),
+            FilterExpr::Bool(false),
+        );
+        assert_optimizes(
+            FilterExpr::Xor(
+                Box::new(FilterExpr::Bool(true)),
+                Box::new(FilterExpr::Gt(5)),
+            ),
+            not(FilterExpr::Gt(5)),
+        );
+    }
+
+    #[test]
+    fn optimize_composed() {
+        assert_optimizes(
+            composed(),
+            FilterExpr::Or(vec![
+                FilterExpr::And(vec![FilterExpr::Gt(5), FilterExpr::Lt(15)]),
+                not(FilterExpr::Ge(-10)),
+                FilterExpr::Eq(20),
+            ]),
+        );
+    }
+
+    #[test]
+    fn optimize_unbalanced() {
+        let f = (0..50).fold(FilterExpr::Gt(0), |f, i| {
+            not(FilterExpr::Xor(
+                Box::new(FilterExpr::And(vec![not(f), FilterExpr::Lt(i)])),
+                Box::new(FilterExpr::Eq(i)),
+            ))
+        });
+        let ((g, g_nodes), _) = f.clone().optimized();
+        assert_eq!(g.node_count(), g_nodes);
+
+        for x in -5..55 {
+            assert_eq!(f.filter(&x), g.filter(&x), "decision differs for {}", x);
+        }
+    }
+
+    #[test]
+    fn optimize_keeps_optimal_trees() {
+        let f = FilterExpr::Or(vec![not(FilterExpr::Gt(5)), FilterExpr::Eq(20)]);
+        assert_eq!(f.clone().optimize(), f);
+    }
+
+    #[test]
+    fn optimize_floats() {
+        let f = not(FilterExpr::And(vec![
+            not(FilterExpr::Gt(1.0)),
+            FilterExpr::Bool(true),
+        ]));
+        let g = f.clone().optimize();
+
+        for x in [f64::NAN, 0.0, 1.0, 2.0] {
+            assert_eq!(f.filter(&x), g.filter(&x));
+        }
+    }
+
+    #[test]
+    fn explain() {
+        let f = FilterExpr::Or(vec![
+            FilterExpr::And(vec![FilterExpr::Gt(5), FilterExpr::Lt(15)]),
+            not(FilterExpr::Eq(20)),
+        ]);
+
+        let leaf = |node: &str, passed| Explanation {
+            node: node.to_string(),
+            passed,
+            children: Vec::new(),
+        };
+
+        let (passed, explanation) = f.filter_explain(&20);
+        assert!(!passed);
+        assert_eq!(
+            explanation,
+            Explanation {
+                node: "or".to_string(),
+                passed: false,
+                children: vec![
+                    Explanation {
+                        node: "and".to_string(),
+                        passed: false,
+                        children: vec![leaf("gt(5)", true), leaf("lt(15)", false)],
+                    },
+                    Explanation {
+                        node: "not".to_string(),
+                        passed: false,
+                        children: vec![leaf("eq(20)", true)],
+                    },
+                ],
+            }
+        );
+        assert_eq!(
+            explanation.to_string(),
+            "fail or\n  fail and\n    pass gt(5)\n    fail lt(15)\n  fail not\n    pass eq(20)\n"
+        );
+    }
+
+    #[test]
+    fn explain_agrees_with_filter() {
+        let f = composed();
+
+        for x in -15..25 {
+            assert_eq!(f.filter_explain(&x).0, f.filter(&x));
+        }
+    }
+
+    #[test]
+    fn unknown_variant() {
+        assert!(serde_json::from_str::<FilterExpr<i64>>(r#"{"between":[1,2]}"#).is_err());
+    }
+}
@@ -0,0 +1,145 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Rendering filters into SQL `WHERE` fragments
+//!
+//! Filters built from the supported leaf filters and the `And`, `Or` and `Not` combinators can
+//! be rendered into a SQL fragment over a named column, so the filtering can be pushed down to a
+//! database:
+//!
+//! ```
+//! use filters::filter::Filter;
+//! use filters::ops::cmp::{GreaterThan, LessThan};
+//! use filters::sql::SqlRenderable;
+//!
+//! let f = GreaterThan(5).and(LessThan(20));
+//!
+//! assert_eq!(f.to_sql("age"), "(age > 5 AND age < 20)");
+//! ```
+//!
+//! Closures cannot be rendered, so a filter tree containing one does not implement
+//! `SqlRenderable`.
+//!
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+
+/// Trait for filters which can be rendered into a SQL `WHERE` fragment
+pub trait SqlRenderable {
+    /// Render the filter as a condition on `column`
+    fn to_sql(&self, column: &str) -> String;
+}
+
+/// Trait for values which can be rendered as a SQL literal
+///
+/// # Panics
+///
+/// SQL has no literals for NaN and the infinities, so rendering a non-finite `f32` or `f64`
+/// panics.
+pub trait SqlLiteral {
+    fn to_sql_literal(&self) -> String;
+}
+
+macro_rules! impl_sql_literal_display {
+    ($($t:ty),*) => {
+        $(
+            impl SqlLiteral for $t {
+                fn to_sql_literal(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_sql_literal_display!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_sql_literal_float {
+    ($($t:ty),*) => {
+        $(
+            impl SqlLiteral for $t {
+                fn to_sql_literal(&self) -> String {
+                    assert!(self.is_finite(), "{} has no SQL literal", self);
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_sql_literal_float!(f32, f64);
+
+impl SqlLiteral for bool {
+    fn to_sql_literal(&self) -> String {
+        if *self { "TRUE" } else { "FALSE" }.to_string()
+    }
+}
+
+/// Strings are quoted, with embedded single quotes escaped by doubling them
+impl SqlLiteral for str {
+    fn to_sql_literal(&self) -> String {
+        format!("'{}'", self.replace('\'', "''"))
+    }
+}
+
+impl SqlLiteral for String {
+    fn to_sql_literal(&self) -> String {
+        self.as_str().to_sql_literal()
+    }
+}
+
+impl<T: SqlLiteral + ?Sized> SqlLiteral for &T {
+    fn to_sql_literal(&self) -> String {
+        (**self).to_sql_literal()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::filter::Filter;
+    use crate::ops::bool::Bool;
+    use crate::ops::cmp::{Equal, GreaterThan, LessThan};
+
+    #[test]
+    fn leaves() {
+        assert_eq!(GreaterThan(5).to_sql("age"), "age > 5");
+        assert_eq!(LessThan(2.5).to_sql("score"), "score < 2.5");
+        assert_eq!(Equal("it's").to_sql("name"), "name = 'it''s'");
+        assert_eq!(Bool::new(false).to_sql("age"), "FALSE");
+    }
+
+    #[test]
+    fn and_of_leaves() {
+        let f = GreaterThan(5).and(LessThan(20));
+        assert_eq!(f.to_sql("age"), "(age > 5 AND age < 20)");
+    }
+
+    #[test]
+    fn nested_tree() {
+        let f = GreaterThan(5)
+            .and(LessThan(20))
+            .or(Equal(42))
+            .and_not(Equal(13));
+
+        assert_eq!(
+            f.to_sql("age"),
+            "(((age > 5 AND age < 20) OR age = 42) AND (NOT age = 13))"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN has no SQL literal")]
+    fn nan_is_rejected() {
+        let _ = LessThan(f64::NAN).to_sql("score");
+    }
+
+    #[test]
+    #[should_panic(expected = "inf has no SQL literal")]
+    fn infinity_is_rejected() {
+        let _ = GreaterThan(f32::INFINITY).to_sql("score");
+    }
+}
@@ -0,0 +1,39 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Fixtures shared by the tests of several modules.
+//!
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::filter::BoxedFilter;
+use crate::filter::Filter;
+
+/// Filter which passes only for `i`
+pub struct EqTo {
+    pub i: usize,
+}
+
+impl Filter<usize> for EqTo {
+    fn filter(&self, n: &usize) -> bool {
+        self.i == *n
+    }
+}
+
+/// Filters passing for numbers greater than 5, even numbers and multiples of 3, in this order
+#[cfg(feature = "alloc")]
+pub fn checks() -> Vec<BoxedFilter<usize>> {
+    vec![
+        Box::new(|&a: &usize| a > 5),
+        Box::new(|&a: &usize| a % 2 == 0),
+        Box::new(|&a: &usize| a % 3 == 0),
+    ]
+}